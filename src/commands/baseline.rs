@@ -0,0 +1,282 @@
+use std::{collections::BTreeMap, fmt::Display, path::PathBuf, time::Duration};
+
+use anyhow::{Context, Result};
+use tracing::debug;
+
+use crate::commands::bench::BenchStats;
+
+// |t| above this is treated as a real change, roughly a 95% confidence level
+const SIGNIFICANCE_THRESHOLD: f64 = 2.0;
+// Changes smaller than this are never flagged, no matter how stable the samples are
+const NOISE_THRESHOLD: f64 = 0.02;
+
+/// Saved bench results keyed by label, year, day, input and part. Part 0 is the parse step
+pub struct BenchBaselines {
+    path: PathBuf,
+    compare: Option<String>,
+    save: Option<String>,
+    input: String,
+    baselines: BTreeMap<(String, u16, u8, String, u8), BenchStats>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Comparison {
+    Regressed(f64),
+    Improved(f64),
+    Unchanged(f64),
+    Missing,
+}
+
+impl BenchBaselines {
+    const BASELINE_FILE: &'static str = "bench_baselines.txt";
+    const REAL_INPUT: &'static str = "real";
+
+    pub fn load(
+        data_save_path: &str,
+        compare: Option<String>,
+        save: Option<String>,
+        input: Option<String>,
+    ) -> Result<Self> {
+        let input = input.unwrap_or_else(|| Self::REAL_INPUT.into());
+        if let Some(label) = save.as_ref().filter(|l| l.contains(char::is_whitespace)) {
+            anyhow::bail!("Baseline label '{}' cannot contain whitespace", label);
+        }
+        if input.contains(char::is_whitespace) {
+            anyhow::bail!("Input name '{}' cannot contain whitespace", input);
+        }
+
+        let path = PathBuf::from(data_save_path).join(Self::BASELINE_FILE);
+        debug!("Loading bench baselines from {:?}", path);
+
+        let mut baselines = BTreeMap::new();
+
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            for (index, line) in content.lines().enumerate().filter(|(_, l)| !l.is_empty()) {
+                let parts: Vec<_> = line.split_whitespace().collect();
+                let [
+                    label,
+                    year,
+                    day,
+                    input,
+                    part,
+                    samples,
+                    min,
+                    median,
+                    mean,
+                    p95,
+                    stddev,
+                    outliers,
+                ] = parts[..]
+                else {
+                    anyhow::bail!("Malformed baseline line {}: '{}'", index + 1, line);
+                };
+
+                let nanos = |s: &str| -> Result<Duration> {
+                    Ok(Duration::from_nanos(s.parse().with_context(|| {
+                        format!("Malformed baseline line {}: '{}'", index + 1, line)
+                    })?))
+                };
+
+                let stats = BenchStats {
+                    samples: samples.parse()?,
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    mean: nanos(mean)?,
+                    p95: nanos(p95)?,
+                    stddev: nanos(stddev)?,
+                    outliers: outliers.parse()?,
+                };
+
+                baselines.insert(
+                    (
+                        label.into(),
+                        year.parse()?,
+                        day.parse()?,
+                        input.into(),
+                        part.parse()?,
+                    ),
+                    stats,
+                );
+            }
+        }
+
+        Ok(Self {
+            path,
+            compare,
+            save,
+            input,
+            baselines,
+        })
+    }
+
+    /// Compares against the selected baseline, and records the stats if saving a baseline
+    pub fn check(
+        &mut self,
+        day: u8,
+        year: u16,
+        part: u8,
+        stats: &BenchStats,
+    ) -> Result<Option<Comparison>> {
+        let comparison = self.compare.as_ref().map(|label| {
+            match self
+                .baselines
+                .get(&(label.clone(), year, day, self.input.clone(), part))
+            {
+                Some(baseline) => Comparison::compare(baseline, stats),
+                None => Comparison::Missing,
+            }
+        });
+
+        if let Some(label) = &self.save {
+            self.baselines.insert(
+                (label.clone(), year, day, self.input.clone(), part),
+                stats.clone(),
+            );
+            self.save()?;
+        }
+
+        Ok(comparison)
+    }
+
+    fn save(&self) -> Result<()> {
+        use std::fmt::Write;
+        let mut content = String::new();
+
+        for ((label, year, day, input, part), stats) in &self.baselines {
+            writeln!(
+                content,
+                "{} {} {:02} {} {} {} {} {} {} {} {} {}",
+                label,
+                year,
+                day,
+                input,
+                part,
+                stats.samples,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.p95.as_nanos(),
+                stats.stddev.as_nanos(),
+                stats.outliers
+            )?;
+        }
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, content)?;
+        Ok(())
+    }
+}
+
+impl Comparison {
+    /// Welch's t-test on the two means, using the sample standard deviations
+    pub fn compare(baseline: &BenchStats, current: &BenchStats) -> Self {
+        let old_mean = baseline.mean.as_nanos() as f64;
+        let new_mean = current.mean.as_nanos() as f64;
+        let delta = (new_mean - old_mean) / old_mean;
+
+        let old_var = baseline.stddev.as_nanos().pow(2) as f64 / baseline.samples as f64;
+        let new_var = current.stddev.as_nanos().pow(2) as f64 / current.samples as f64;
+        let standard_error = (old_var + new_var).sqrt();
+
+        let significant = match standard_error > 0.0 {
+            true => ((new_mean - old_mean) / standard_error).abs() > SIGNIFICANCE_THRESHOLD,
+            false => true,
+        };
+
+        match (significant && delta.abs() > NOISE_THRESHOLD, delta > 0.0) {
+            (true, true) => Self::Regressed(delta),
+            (true, false) => Self::Improved(delta),
+            (false, _) => Self::Unchanged(delta),
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Comparison::Regressed(_) => "REGRESSED",
+            Comparison::Improved(_) => "IMPROVED",
+            Comparison::Unchanged(_) => "UNCHANGED",
+            Comparison::Missing => "NO BASELINE",
+        }
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Comparison::Regressed(delta) => write!(f, "[REGRESSED] {:+.2}%", delta * 100.0),
+            Comparison::Improved(delta) => write!(f, "[IMPROVED] {:+.2}%", delta * 100.0),
+            Comparison::Unchanged(delta) => write!(f, "[UNCHANGED] {:+.2}%", delta * 100.0),
+            Comparison::Missing => write!(f, "[NO BASELINE]"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{commands::InputFetcher, test_utils::TempDir};
+
+    fn stats(mean_micros: u64, stddev_micros: u64) -> BenchStats {
+        BenchStats {
+            samples: 100,
+            min: Duration::from_micros(mean_micros),
+            median: Duration::from_micros(mean_micros),
+            mean: Duration::from_micros(mean_micros),
+            p95: Duration::from_micros(mean_micros),
+            stddev: Duration::from_micros(stddev_micros),
+            outliers: 0,
+        }
+    }
+
+    #[test]
+    fn compare_significance() {
+        assert!(matches!(
+            Comparison::compare(&stats(100, 5), &stats(120, 5)),
+            Comparison::Regressed(_)
+        ));
+        assert!(matches!(
+            Comparison::compare(&stats(100, 5), &stats(80, 5)),
+            Comparison::Improved(_)
+        ));
+        // Too noisy to tell the difference
+        assert!(matches!(
+            Comparison::compare(&stats(100, 80), &stats(110, 80)),
+            Comparison::Unchanged(_)
+        ));
+    }
+
+    #[test]
+    fn save_and_compare() {
+        let data_path = TempDir::new("baseline");
+        let data_path_str = data_path.to_str().unwrap();
+
+        let real = InputFetcher::input_name(None, &None);
+        let test = InputFetcher::input_name(Some(1), &None);
+
+        let mut baselines =
+            BenchBaselines::load(data_path_str, None, Some("before".into()), real.clone()).unwrap();
+        assert_eq!(baselines.check(1, 2023, 1, &stats(100, 5)).unwrap(), None);
+
+        let mut test_baselines =
+            BenchBaselines::load(data_path_str, Some("before".into()), None, test).unwrap();
+        let mut baselines =
+            BenchBaselines::load(data_path_str, Some("before".into()), None, real).unwrap();
+
+        // The example is much faster, but has no baseline of its own
+        assert_eq!(
+            test_baselines.check(1, 2023, 1, &stats(10, 5)).unwrap(),
+            Some(Comparison::Missing)
+        );
+
+        assert!(matches!(
+            baselines.check(1, 2023, 1, &stats(150, 5)).unwrap(),
+            Some(Comparison::Regressed(_))
+        ));
+        assert_eq!(
+            baselines.check(1, 2023, 2, &stats(150, 5)).unwrap(),
+            Some(Comparison::Missing)
+        );
+    }
+}
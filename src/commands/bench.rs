@@ -1,57 +1,290 @@
 use anyhow::Result;
-use std::time::{Duration, Instant};
+use serde::Deserialize;
+use std::{
+    any::Any,
+    fmt::Display,
+    time::{Duration, Instant},
+};
 
-use crate::events;
+use crate::{
+    commands::{
+        baseline::{BenchBaselines, Comparison},
+        output::{Output, Record},
+    },
+    events::{self, RunContext},
+};
 use tracing::{debug, info};
 
-const ITERATION_CUTOFF: u32 = 100;
+const ITERATION_CUTOFF: usize = 100;
 const TIME_CUTOFF: Duration = Duration::from_secs(1);
+const WARMUP_ITERATION_CUTOFF: usize = 10;
+const WARMUP_TIME_CUTOFF: Duration = Duration::from_millis(200);
 
-pub fn bench_day(day: u8, year: u16, input: Vec<String>, part: Option<u8>) -> Result<()> {
+/// When to stop sampling, whichever is hit first
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BenchCutoffs {
+    pub iterations: usize,
+    pub time_ms: u64,
+}
+
+impl Default for BenchCutoffs {
+    fn default() -> Self {
+        Self {
+            iterations: ITERATION_CUTOFF,
+            time_ms: TIME_CUTOFF.as_millis() as u64,
+        }
+    }
+}
+
+pub fn bench_day(
+    day: u8,
+    year: u16,
+    input: Vec<String>,
+    ctx: &RunContext,
+    baselines: &mut BenchBaselines,
+    output: &mut Output,
+    cutoffs: BenchCutoffs,
+) -> Result<()> {
     info!("Running: Day {} Year {}", day, year);
-    match part {
-        Some(1) => part1(day, year, &input),
-        Some(2) => part2(day, year, &input),
+    let input = parse(day, year, &input, ctx, baselines, output, cutoffs)?;
+    let input = input.as_ref();
+
+    match ctx.part {
+        Some(1) => part1(day, year, input, ctx, baselines, output, cutoffs),
+        Some(2) => part2(day, year, input, ctx, baselines, output, cutoffs),
         _ => {
-            part1(day, year, &input)?;
-            part2(day, year, &input)
+            part1(day, year, input, ctx, baselines, output, cutoffs)?;
+            part2(day, year, input, ctx, baselines, output, cutoffs)
         }
     }
 }
 
-fn part1(day: u8, year: u16, input: &Vec<String>) -> Result<()> {
+/// Measures the parse step, handing back the last parsed input for the parts
+fn parse(
+    day: u8,
+    year: u16,
+    input: &[String],
+    ctx: &RunContext,
+    baselines: &mut BenchBaselines,
+    output: &mut Output,
+    cutoffs: BenchCutoffs,
+) -> Result<Box<dyn Any>> {
     let mut aoc_day = events::get_day(day, year)?;
 
-    let start = Instant::now();
-    let mut iterations = 0;
-    let part1 = loop {
-        let part1 = std::hint::black_box(aoc_day.run_part1(input)?);
-        iterations += 1;
-        if start.elapsed() > TIME_CUTOFF || iterations > ITERATION_CUTOFF {
-            break part1;
-        }
-    };
+    let (parsed, stats) = measure(cutoffs, || aoc_day.parse_any(input, ctx))?;
 
-    info!("--Part1: Took {:?}", start.elapsed() / iterations);
+    let comparison = baselines.check(day, year, 0, &stats)?;
+    match &comparison {
+        Some(comparison) => info!("--Parse: {} {}", stats, comparison),
+        None => info!("--Parse: {}", stats),
+    }
+
+    output.emit(&record(day, year, 0, None, &stats, comparison));
+    Ok(parsed)
+}
+
+fn part1(
+    day: u8,
+    year: u16,
+    input: &dyn Any,
+    ctx: &RunContext,
+    baselines: &mut BenchBaselines,
+    output: &mut Output,
+    cutoffs: BenchCutoffs,
+) -> Result<()> {
+    let mut aoc_day = events::get_day(day, year)?;
+
+    let (part1, stats) = measure(cutoffs, || aoc_day.run_part1_any(input, ctx))?;
+
+    let comparison = baselines.check(day, year, 1, &stats)?;
+    match &comparison {
+        Some(comparison) => info!("--Part1: {} {}", stats, comparison),
+        None => info!("--Part1: {}", stats),
+    }
 
     debug!("--Part1: '{}'", part1);
+    output.emit(&record(
+        day,
+        year,
+        1,
+        Some(part1.to_string()),
+        &stats,
+        comparison,
+    ));
     Ok(())
 }
 
-fn part2(day: u8, year: u16, input: &Vec<String>) -> Result<()> {
+fn part2(
+    day: u8,
+    year: u16,
+    input: &dyn Any,
+    ctx: &RunContext,
+    baselines: &mut BenchBaselines,
+    output: &mut Output,
+    cutoffs: BenchCutoffs,
+) -> Result<()> {
     let mut aoc_day = events::get_day(day, year)?;
 
+    let (part2, stats) = measure(cutoffs, || aoc_day.run_part2_any(input, ctx))?;
+
+    let comparison = baselines.check(day, year, 2, &stats)?;
+    match &comparison {
+        Some(comparison) => info!("--Part2: {} {}", stats, comparison),
+        None => info!("--Part2: {}", stats),
+    }
+
+    debug!("--Part2: '{}'", part2);
+    output.emit(&record(
+        day,
+        year,
+        2,
+        Some(part2.to_string()),
+        &stats,
+        comparison,
+    ));
+    Ok(())
+}
+
+fn record(
+    day: u8,
+    year: u16,
+    part: u8,
+    answer: Option<String>,
+    stats: &BenchStats,
+    comparison: Option<Comparison>,
+) -> Record {
+    Record {
+        year,
+        day,
+        part,
+        answer,
+        duration: stats.mean,
+        iterations: stats.samples,
+        status: comparison.map_or("OK", |c| c.label()),
+    }
+}
+
+/// Runs `f` through a warmup phase, then samples every iteration until one of the cutoffs
+fn measure<T, F: FnMut() -> Result<T>>(cutoffs: BenchCutoffs, mut f: F) -> Result<(T, BenchStats)> {
     let start = Instant::now();
     let mut iterations = 0;
-    let part2 = loop {
-        let part2 = std::hint::black_box(aoc_day.run_part2(input)?);
+    while iterations < WARMUP_ITERATION_CUTOFF && start.elapsed() < WARMUP_TIME_CUTOFF {
+        std::hint::black_box(f()?);
         iterations += 1;
-        if start.elapsed() > TIME_CUTOFF || iterations > ITERATION_CUTOFF {
-            break part2;
+    }
+
+    let start = Instant::now();
+    let mut samples = vec![];
+    let result = loop {
+        let sample_start = Instant::now();
+        let result = std::hint::black_box(f()?);
+        samples.push(sample_start.elapsed());
+
+        if start.elapsed() > Duration::from_millis(cutoffs.time_ms)
+            || samples.len() >= cutoffs.iterations
+        {
+            break result;
         }
     };
-    info!("--Part2: Took {:?}", start.elapsed() / iterations);
 
-    debug!("--Part2: '{}'", part2);
-    Ok(())
+    Ok((result, BenchStats::from_samples(samples)))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchStats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "cannot compute stats without samples");
+        samples.sort();
+
+        let nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = match nanos.len() {
+            1 => 0.0,
+            len => nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (len - 1) as f64,
+        };
+
+        // Tukey's fences, anything further than 1.5 IQR outside the quartiles is an outlier
+        let q1 = percentile(&nanos, 0.25);
+        let q3 = percentile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let outliers = nanos
+            .iter()
+            .filter(|&&n| n < q1 - 1.5 * iqr || n > q3 + 1.5 * iqr)
+            .count();
+
+        Self {
+            samples: samples.len(),
+            min: samples[0],
+            median: Duration::from_nanos(percentile(&nanos, 0.5) as u64),
+            mean: Duration::from_nanos(mean as u64),
+            p95: Duration::from_nanos(percentile(&nanos, 0.95) as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+            outliers,
+        }
+    }
+}
+
+// Linear interpolation between the closest ranks of the sorted samples
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+impl Display for BenchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "mean {:?} ± {:?} | min {:?} | median {:?} | p95 {:?} | {} samples",
+            self.mean, self.stddev, self.min, self.median, self.p95, self.samples
+        )?;
+
+        if self.outliers > 0 {
+            write!(f, ", {} outliers", self.outliers)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_from_samples() {
+        let mut samples: Vec<_> = (1..=10).map(Duration::from_millis).collect();
+        samples.push(Duration::from_millis(100));
+
+        let stats = BenchStats::from_samples(samples);
+
+        assert_eq!(stats.samples, 11);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(6));
+        assert_eq!(stats.mean.as_micros(), 14090);
+        assert_eq!(stats.p95, Duration::from_millis(55));
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = BenchStats::from_samples(vec![Duration::from_micros(5)]);
+
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
 }
@@ -0,0 +1,105 @@
+use std::{io::Write, path::PathBuf, time::Duration};
+
+use reqwest::{
+    Method, Url,
+    blocking::{Client, RequestBuilder, Response},
+};
+use tracing::debug;
+
+use crate::commands::error::FetchError;
+
+/// Shared HTTP client that identifies itself and keeps a polite pace, even across runs
+pub struct AocClient {
+    client: Client,
+    data_save_path: PathBuf,
+}
+
+impl AocClient {
+    const MIN_INTERVAL: Duration = Duration::from_secs(2);
+    const LAST_REQUEST_FILE: &'static str = "last_request.txt";
+    const REQUEST_LOG_FILE: &'static str = "requests.log";
+
+    pub fn new(data_save_path: &str, user_agent: &str) -> Result<Self, FetchError> {
+        // Redirects only ever lead to the login page, which should not be mistaken for content
+        let client = Client::builder()
+            .user_agent(user_agent)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()?;
+
+        Ok(Self {
+            client,
+            data_save_path: data_save_path.into(),
+        })
+    }
+
+    pub fn get(&self, url: &str, cookie: &str) -> Result<Response, FetchError> {
+        self.send(
+            self.client
+                .get(url)
+                .header("Cookie", format!("session={}", cookie)),
+        )
+    }
+
+    pub fn post_form(
+        &self,
+        url: &str,
+        cookie: &str,
+        form: &[(&str, String)],
+    ) -> Result<Response, FetchError> {
+        self.send(
+            self.client
+                .post(url)
+                .header("Cookie", format!("session={}", cookie))
+                .form(form),
+        )
+    }
+
+    fn send(&self, request: RequestBuilder) -> Result<Response, FetchError> {
+        let request = request.build()?;
+        let (method, url) = (request.method().clone(), request.url().clone());
+
+        self.throttle()?;
+        let resp = self.client.execute(request);
+        self.log(&method, &url, resp.as_ref().ok())?;
+
+        Ok(resp?)
+    }
+
+    /// Waits until the minimum interval since the last request, in this run or a previous one, has passed
+    fn throttle(&self) -> Result<(), FetchError> {
+        let path = self.data_save_path.join(Self::LAST_REQUEST_FILE);
+
+        let last = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|last| last.trim().parse::<i64>().ok());
+        if let Some(last) = last {
+            let next = last + Self::MIN_INTERVAL.as_millis() as i64;
+            let wait = next - chrono::Utc::now().timestamp_millis();
+            if wait > 0 {
+                debug!("Throttling request for {}ms", wait);
+                std::thread::sleep(Duration::from_millis(wait as u64));
+            }
+        }
+
+        std::fs::create_dir_all(&self.data_save_path)?;
+        std::fs::write(&path, chrono::Utc::now().timestamp_millis().to_string())?;
+        Ok(())
+    }
+
+    fn log(&self, method: &Method, url: &Url, resp: Option<&Response>) -> Result<(), FetchError> {
+        let mut log = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.data_save_path.join(Self::REQUEST_LOG_FILE))?;
+
+        writeln!(
+            log,
+            "{} {} {} {}",
+            chrono::Utc::now().to_rfc3339(),
+            method,
+            url,
+            resp.map_or("FAILED".into(), |r| r.status().as_u16().to_string())
+        )?;
+        Ok(())
+    }
+}
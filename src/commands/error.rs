@@ -0,0 +1,89 @@
+use std::{fmt::Display, path::PathBuf};
+
+use reqwest::StatusCode;
+
+use crate::commands::unlock;
+
+#[derive(Debug)]
+pub enum FetchError {
+    NotLoggedIn,
+    NoUserAgent,
+    NotUnlocked { day: u8, year: u16 },
+    NoSuchDay { day: u8, year: u16 },
+    HttpStatus(StatusCode),
+    NetworkDown(reqwest::Error),
+    MissingOverride(PathBuf),
+    MissingExample { index: usize, count: usize },
+    Offline(PathBuf),
+    Io(std::io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::NotLoggedIn => {
+                write!(
+                    f,
+                    "Not logged in, the session cookie is missing or expired. Set AOC_SESSION or run interactively to log in"
+                )
+            }
+            FetchError::NoUserAgent => write!(
+                f,
+                "No User-Agent set, add user_agent to aoc.toml or pass --user-agent with your repo or email so the Advent of Code team can reach you"
+            ),
+            FetchError::NotUnlocked { day, year } => {
+                write!(f, "Day {} Year {} is not unlocked yet", day, year)?;
+                match unlock::unlock_time(*day, *year) {
+                    Some(unlock) => write!(f, ", it unlocks at {} (use --wait)", unlock),
+                    None => Ok(()),
+                }
+            }
+            FetchError::NoSuchDay { day, year } => write!(
+                f,
+                "Day {} does not exist, {} only has {} days",
+                day,
+                year,
+                unlock::days_in_event(*year)
+            ),
+            FetchError::HttpStatus(status) => write!(f, "Server responded with {}", status),
+            FetchError::NetworkDown(err) => write!(f, "Could not reach the server: {}", err),
+            FetchError::MissingOverride(path) => {
+                write!(f, "Failed to find specific input file: {:?}", path)
+            }
+            FetchError::MissingExample { index, count } => write!(
+                f,
+                "Example {} does not exist, the puzzle page has {} examples",
+                index, count
+            ),
+            FetchError::Offline(path) => {
+                write!(f, "Nothing cached at {:?} and running offline", path)
+            }
+            FetchError::Io(err) => write!(f, "Failed to access the input cache: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FetchError::NetworkDown(err) => Some(err),
+            FetchError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for FetchError {
+    fn from(err: reqwest::Error) -> Self {
+        match err.status() {
+            Some(status) => FetchError::HttpStatus(status),
+            None => FetchError::NetworkDown(err),
+        }
+    }
+}
+
+impl From<std::io::Error> for FetchError {
+    fn from(err: std::io::Error) -> Self {
+        FetchError::Io(err)
+    }
+}
@@ -0,0 +1,107 @@
+use crate::commands::html::{self, strip_tags};
+
+/// The example inputs on a puzzle page, along with the answers the text gives for them
+pub struct Examples {
+    pub inputs: Vec<Vec<String>>,
+    /// Example index, part and answer
+    pub answers: Vec<(usize, u8, String)>,
+}
+
+impl Examples {
+    pub fn parse(page: &str) -> Self {
+        let blocks = html::sections(page, "<pre><code>", "</code></pre>");
+
+        let inputs = blocks
+            .iter()
+            .map(|(_, block)| strip_tags(block).lines().map(|s| s.into()).collect())
+            .collect();
+
+        let mut answers = vec![];
+        let mut example = None;
+        let articles = html::sections(page, "<article class=\"day-desc\">", "</article>");
+        for (part, (start, article)) in articles.into_iter().enumerate() {
+            // Each part uses the first example it shows, the blocks after it are worked steps.
+            // A part without an example of its own reuses the one before it
+            let end = start + article.len();
+            if let Some(index) = blocks.iter().position(|&(b, _)| (start..end).contains(&b)) {
+                example = Some(index);
+            }
+
+            // The example answer is the last highlighted code in each part
+            let Some(&(_, answer)) = html::sections(article, "<code><em>", "</em></code>").last()
+            else {
+                continue;
+            };
+
+            if let Some(index) = example {
+                answers.push((index, part as u8 + 1, strip_tags(answer)));
+            }
+        }
+
+        Self { inputs, answers }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_examples() {
+        let page = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>two1nine
+<em>eight</em>wothree
+</code></pre>
+<p>The values are <code>29</code> and <code>83</code>, which sum to <code><em>281</em></code>.</p>
+</article>
+</main>"#;
+
+        let examples = Examples::parse(page);
+
+        assert_eq!(
+            examples.inputs,
+            vec![
+                vec!["1abc2", "pqr3stu8vwx"],
+                vec!["two1nine", "eightwothree"]
+            ]
+        );
+        assert_eq!(
+            examples.answers,
+            vec![(0, 1, "142".into()), (1, 2, "281".into())]
+        );
+    }
+
+    #[test]
+    fn answers_belong_to_the_first_example() {
+        let page = r#"<main>
+<article class="day-desc"><h2>--- Day 14: Parabolic Reflector Dish ---</h2>
+<pre><code>O.#
+.O.
+</code></pre>
+<p>Tilting the platform north moves the rocks:</p>
+<pre><code>OO#
+...
+</code></pre>
+<p>The total load is <code><em>136</em></code>.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>After a cycle the load is <code><em>64</em></code>.</p>
+</article>
+</main>"#;
+
+        let examples = Examples::parse(page);
+
+        assert_eq!(examples.inputs.len(), 2);
+        assert_eq!(
+            examples.answers,
+            vec![(0, 1, "136".into()), (0, 2, "64".into())]
+        );
+    }
+}
@@ -0,0 +1,147 @@
+pub fn strip_tags(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_tag = false;
+
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => stripped.push(c),
+            _ => (),
+        }
+    }
+
+    html_escape::decode_html_entities(&stripped).into_owned()
+}
+
+/// Every section between `open` and `close`, along with where it starts in `text`
+pub fn sections<'a>(text: &'a str, open: &str, close: &str) -> Vec<(usize, &'a str)> {
+    let mut sections = vec![];
+    let mut offset = 0;
+
+    while let Some(start) = text[offset..].find(open).map(|s| offset + s + open.len()) {
+        let Some(end) = text[start..].find(close).map(|e| start + e) else {
+            break;
+        };
+
+        sections.push((start, &text[start..end]));
+        offset = end + close.len();
+    }
+
+    sections
+}
+
+/// Converts the puzzle description articles of a page to Markdown
+pub fn puzzle_markdown(page: &str) -> String {
+    sections(page, "<article class=\"day-desc\">", "</article>")
+        .into_iter()
+        .map(|(_, article)| to_markdown(article))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The title from the `--- Day 1: Title ---` heading of a puzzle converted to Markdown
+pub fn puzzle_title(markdown: &str) -> Option<&str> {
+    let heading = markdown
+        .lines()
+        .find(|line| line.starts_with("## --- Day "))?;
+    let (_, title) = heading.split_once(": ")?;
+    Some(title.trim_end_matches(" ---").trim())
+}
+
+/// Handles the small subset of HTML used on puzzle pages
+pub fn to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut link = None;
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        push_text(&mut markdown, &rest[..start], in_pre);
+
+        let Some(end) = rest[start..].find('>').map(|e| start + e) else {
+            break;
+        };
+        let tag = &rest[start + 1..end];
+        let name = tag.split_whitespace().next().unwrap_or_default();
+        rest = &rest[end + 1..];
+
+        // Highlighted code reads better as bold code than as code with literal asterisks
+        if name == "code" && rest.starts_with("<em>") {
+            markdown.push_str("**`");
+            rest = &rest["<em>".len()..];
+            continue;
+        }
+        if name == "/em" && rest.starts_with("</code>") {
+            markdown.push_str("`**");
+            rest = &rest["</code>".len()..];
+            continue;
+        }
+
+        match (in_pre, name) {
+            (false, "pre") => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            (true, "/pre") => {
+                in_pre = false;
+                markdown.push_str("```\n\n");
+            }
+            (true, _) => (),
+            (false, "h2") => markdown.push_str("## "),
+            (false, "/h2" | "/p" | "/ul") => markdown.push_str("\n\n"),
+            (false, "li") => markdown.push_str("- "),
+            (false, "/li") => markdown.push('\n'),
+            (false, "code" | "/code") => markdown.push('`'),
+            (false, "em" | "/em") => markdown.push('*'),
+            (false, "a") => {
+                link = tag
+                    .split("href=\"")
+                    .nth(1)
+                    .and_then(|href| href.split('"').next())
+                    .map(String::from);
+                markdown.push('[');
+            }
+            (false, "/a") => {
+                markdown.push_str(&format!("]({})", link.take().unwrap_or_default()));
+            }
+            _ => (),
+        }
+    }
+    push_text(&mut markdown, rest, in_pre);
+
+    markdown.trim_end().to_string() + "\n"
+}
+
+fn push_text(markdown: &mut String, text: &str, in_pre: bool) {
+    // Line breaks between block elements are only formatting of the HTML itself
+    if !in_pre && text.contains('\n') && text.trim().is_empty() {
+        return;
+    }
+    markdown.push_str(&html_escape::decode_html_entities(text));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markdown() {
+        let article = r#"<h2>--- Day 1: Trebuchet?! ---</h2><p>The <a href="/2023/about" target="_blank">calibration</a> value is <em>not</em> <code>a &lt; b</code>:</p>
+<pre><code>1abc2
+<em>pqr3stu8vwx</em>
+</code></pre>
+<ul>
+<li>Sum is <code><em>142</em></code>.</li>
+</ul>"#;
+
+        assert_eq!(puzzle_title(&to_markdown(article)), Some("Trebuchet?!"));
+        assert_eq!(
+            to_markdown(article),
+            "## --- Day 1: Trebuchet?! ---\n\n\
+             The [calibration](/2023/about) value is *not* `a < b`:\n\n\
+             ```\n1abc2\npqr3stu8vwx\n```\n\n\
+             - Sum is **`142`**.\n"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use std::{collections::BTreeMap, fmt::Display, path::PathBuf};
+
+use anyhow::{Context, Result};
+use tracing::debug;
+
+use crate::utils::AoCResult;
+
+/// Known correct answers, stored as `year day part answer` lines next to the input cache
+pub struct AnswerLedger {
+    path: PathBuf,
+    answers: BTreeMap<(u16, u8, u8), String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LedgerStatus {
+    Pass,
+    Fail(String),
+    New,
+}
+
+impl AnswerLedger {
+    const LEDGER_FILE: &'static str = "answers.txt";
+
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            answers: BTreeMap::new(),
+        }
+    }
+
+    pub fn load(data_save_path: &str) -> Result<Self> {
+        Self::load_file(PathBuf::from(data_save_path).join(Self::LEDGER_FILE))
+    }
+
+    /// Loads a ledger from any file, like the known answers of an example input
+    pub fn load_file(path: PathBuf) -> Result<Self> {
+        debug!("Loading answer ledger from {:?}", path);
+
+        let mut answers = BTreeMap::new();
+
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            for (index, line) in content.lines().enumerate().filter(|(_, l)| !l.is_empty()) {
+                let mut parts = line.splitn(4, ' ');
+                let mut next = || {
+                    parts
+                        .next()
+                        .with_context(|| format!("Malformed ledger line {}: '{}'", index + 1, line))
+                };
+
+                let year = next()?.parse()?;
+                let day = next()?.parse()?;
+                let part = next()?.parse()?;
+                let answer = next()?.to_string();
+
+                answers.insert((year, day, part), answer);
+            }
+        }
+
+        Ok(Self { path, answers })
+    }
+
+    pub fn get(&self, day: u8, year: u16, part: u8) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(|s| s.as_str())
+    }
+
+    pub fn check(&self, day: u8, year: u16, part: u8, result: &AoCResult) -> LedgerStatus {
+        match self.get(day, year, part) {
+            Some(expected) if expected == result.to_string() => LedgerStatus::Pass,
+            Some(expected) => LedgerStatus::Fail(expected.into()),
+            None => LedgerStatus::New,
+        }
+    }
+
+    pub fn record(&mut self, day: u8, year: u16, part: u8, result: &AoCResult) -> Result<()> {
+        self.insert(day, year, part, result.to_string());
+        Ok(self.save()?)
+    }
+
+    pub fn insert(&mut self, day: u8, year: u16, part: u8, answer: String) {
+        self.answers.insert((year, day, part), answer);
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let mut content = String::new();
+
+        for ((year, day, part), answer) in &self.answers {
+            content.push_str(&format!("{} {:02} {} {}\n", year, day, part, answer));
+        }
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, content)?;
+        Ok(())
+    }
+}
+
+impl LedgerStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LedgerStatus::Pass => "PASS",
+            LedgerStatus::Fail(_) => "FAIL",
+            LedgerStatus::New => "NEW",
+        }
+    }
+}
+
+impl Display for LedgerStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LedgerStatus::Pass => write!(f, "[PASS]"),
+            LedgerStatus::Fail(expected) => write!(f, "[FAIL] expected '{}'", expected),
+            LedgerStatus::New => write!(f, "[NEW]"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TempDir;
+
+    #[test]
+    fn record_and_check() {
+        let data_path = TempDir::new("ledger");
+        let data_path_str = data_path.to_str().unwrap();
+
+        let mut ledger = AnswerLedger::load(data_path_str).unwrap();
+        assert_eq!(
+            ledger.check(1, 2023, 1, &AoCResult::from(142)),
+            LedgerStatus::New
+        );
+
+        ledger.record(1, 2023, 1, &AoCResult::from(142)).unwrap();
+        ledger.record(1, 2023, 2, &AoCResult::from("a b")).unwrap();
+
+        let ledger = AnswerLedger::load(data_path_str).unwrap();
+
+        assert_eq!(
+            ledger.check(1, 2023, 1, &AoCResult::from(142)),
+            LedgerStatus::Pass
+        );
+        assert_eq!(
+            ledger.check(1, 2023, 2, &AoCResult::from("a b")),
+            LedgerStatus::Pass
+        );
+        assert_eq!(
+            ledger.check(1, 2023, 1, &AoCResult::from(281)),
+            LedgerStatus::Fail("142".into())
+        );
+    }
+}
@@ -1,42 +1,92 @@
-use std::{io::Write, path::PathBuf};
+use std::{
+    io::{IsTerminal, Write},
+    path::{Path, PathBuf},
+};
 
-use tracing::{debug, trace};
+use anyhow::Result;
+use reqwest::StatusCode;
+use tracing::{debug, info, trace, warn};
 
+use crate::commands::{
+    client::AocClient, error::FetchError, example::Examples, ledger::AnswerLedger,
+    submit::SubmitOutcome,
+};
+
+pub mod baseline;
 pub mod bench;
+pub mod client;
+pub mod error;
+pub mod example;
+pub mod html;
+pub mod ledger;
+pub mod output;
 pub mod run;
+pub mod submit;
+pub mod unlock;
+pub mod verify;
 
 pub struct InputFetcher {
     data_save_path: String,
-    cookie: String,
+    base_address: String,
+    cookie_file: String,
+    cookie: Option<String>,
+    offline: bool,
+    user_agent: Option<String>,
+    client: Option<AocClient>,
 }
 
 impl InputFetcher {
-    const BASE_ADDRESS: &'static str = "https://adventofcode.com";
-    const COOKIE_FILE: &'static str = "personal.cookie";
+    pub const BASE_ADDRESS: &'static str = "https://adventofcode.com";
+    pub const COOKIE_FILE: &'static str = "personal.cookie";
+    const SESSION_ENV: &'static str = "AOC_SESSION";
 
     pub fn new(data_save_path: &str) -> Self {
-        let mut fetcher = Self {
+        Self {
             data_save_path: data_save_path.into(),
-            cookie: "".into(),
-        };
+            base_address: Self::BASE_ADDRESS.into(),
+            cookie_file: Self::COOKIE_FILE.into(),
+            cookie: None,
+            offline: false,
+            user_agent: None,
+            client: None,
+        }
+    }
+
+    pub fn with_base_address(mut self, base_address: &str) -> Self {
+        self.base_address = base_address.trim_end_matches('/').into();
+        self
+    }
+
+    pub fn with_cookie_file(mut self, cookie_file: &str) -> Self {
+        self.cookie_file = cookie_file.into();
+        self
+    }
+
+    pub fn with_user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
 
-        fetcher.fetch_cookie();
+    /// Uses this session as is, without looking at `AOC_SESSION` or the cookie file
+    pub fn with_session(mut self, session: &str) -> Self {
+        self.cookie = Some(session.into());
+        self
+    }
 
-        fetcher
+    /// Only read from the input cache, never touching the network
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
     }
 
     pub fn fetch(
         &mut self,
         day: u8,
         year: u16,
-        test: bool,
+        test: Option<usize>,
         specific_input: &Option<String>,
-    ) -> Vec<String> {
-        let data_path = PathBuf::from(&self.data_save_path).join(match (test, specific_input) {
-            (_, Some(input)) => format!("{}/{:02}_{}.txt", year, day, input),
-            (true, None) => format!("{}/{:02}_test.txt", year, day),
-            (false, None) => format!("{}/{:02}.txt", year, day),
-        });
+    ) -> Result<Vec<String>, FetchError> {
+        let data_path = Self::input_path(&self.data_save_path, day, year, test, specific_input);
 
         debug!("Feting input from {:?}", data_path);
         match (
@@ -45,106 +95,373 @@ impl InputFetcher {
         ) {
             (Ok(input), _) => {
                 trace!("Found file cahce. Fetching from file");
-                input.lines().map(|s| s.into()).collect()
+                Ok(input.lines().map(|s| s.into()).collect())
             }
+            (Err(_), true) => Err(FetchError::MissingOverride(data_path)),
+            (Err(_), false) if self.offline => Err(FetchError::Offline(data_path)),
             (Err(_), false) => {
                 trace!("Did not find file cahce. Fetching from source");
-                let input = match test {
-                    true => self.fetch_input_test(day, year),
-                    false => self.fetch_input(day, year),
-                };
-                std::fs::create_dir_all(data_path.parent().unwrap())
-                    .expect("expected to create all dirs");
-                std::fs::write(&data_path, input.join("\n"))
-                    .expect("Expected to be able to write inputs");
-
-                input
+                unlock::check_unlocked(day, year, chrono::Utc::now())?;
+                match test {
+                    Some(index) => self.fetch_examples(day, year, index),
+                    None => {
+                        let input = self.fetch_input(day, year)?;
+                        write_input(&data_path, &input)?;
+                        Ok(input)
+                    }
+                }
             }
-            (Err(_), true) => panic!(
-                "Failed to find specific file: {}/{:02}_{}.txt",
-                year,
-                day,
-                specific_input.clone().unwrap()
-            ),
         }
     }
 
-    fn fetch_input(&mut self, day: u8, year: u16) -> Vec<String> {
-        let path = format!("/{year}/day/{day}/input");
+    /// Sleeps until the day unlocks, then caches both the input and the example
+    pub fn wait_and_fetch(&mut self, day: u8, year: u16) -> Result<(), FetchError> {
+        // Ask for the cookie up front rather than after the wait
+        self.cookie()?;
+        unlock::wait_for_unlock(day, year)?;
+
+        self.fetch(day, year, None, &None)?;
+        self.fetch(day, year, Some(1), &None)?;
+        Ok(())
+    }
 
+    pub fn input_path(
+        data_save_path: &str,
+        day: u8,
+        year: u16,
+        test: Option<usize>,
+        specific_input: &Option<String>,
+    ) -> PathBuf {
+        PathBuf::from(data_save_path).join(match Self::input_name(test, specific_input) {
+            Some(input) => format!("{}/{:02}_{}.txt", year, day, input),
+            None => format!("{}/{:02}.txt", year, day),
+        })
+    }
+
+    /// The name an input other than the real one goes by, as used in its file name
+    pub fn input_name(test: Option<usize>, specific_input: &Option<String>) -> Option<String> {
+        match (test, specific_input) {
+            (_, Some(input)) => Some(input.clone()),
+            (Some(1), None) => Some("test".into()),
+            (Some(index), None) => Some(format!("test{}", index)),
+            (None, None) => None,
+        }
+    }
+
+    /// Known answers for an input other than the real one are stored next to it
+    pub fn answers_path(input_path: &Path) -> PathBuf {
+        let stem = input_path.file_stem().unwrap_or_default().to_string_lossy();
+        input_path.with_file_name(format!("{}_answers.txt", stem))
+    }
+
+    fn fetch_input(&mut self, day: u8, year: u16) -> Result<Vec<String>, FetchError> {
+        let path = format!("/{year}/day/{day}/input");
         debug!("Fetching input from path: '{}'", path);
-        let client = reqwest::blocking::Client::new();
 
-        let resp = client
-            .get(format!("{}{}", Self::BASE_ADDRESS, &path))
-            .header("Cookie", format!("session={}", self.cookie))
-            .send()
-            .expect("Expected to perform HTTP call");
+        let text = self.get(day, year, &path)?;
 
-        resp.text()
-            .expect("expected text body")
-            .lines()
-            .map(|s| s.into())
-            .collect()
+        Ok(text.lines().map(|s| s.into()).collect())
     }
 
-    fn fetch_input_test(&mut self, day: u8, year: u16) -> Vec<String> {
+    /// Caches every example on the puzzle page along with their answers, returning the selected one
+    fn fetch_examples(
+        &mut self,
+        day: u8,
+        year: u16,
+        index: usize,
+    ) -> Result<Vec<String>, FetchError> {
         let path = format!("/{year}/day/{day}");
         debug!("Fetching test input from path: '{}'", path);
 
-        let client = reqwest::blocking::Client::new();
+        let examples = Examples::parse(&self.get(day, year, &path)?);
+
+        // Examples and answers that are already cached may have been edited by hand
+        for (i, input) in examples.inputs.iter().enumerate() {
+            let data_path = Self::input_path(&self.data_save_path, day, year, Some(i + 1), &None);
+            if !data_path.exists() {
+                write_input(&data_path, input)?;
+            }
+
+            let answers_path = Self::answers_path(&data_path);
+            if !answers_path.exists() {
+                let mut ledger = AnswerLedger::new(answers_path);
+                for (_, part, answer) in examples.answers.iter().filter(|(a, _, _)| *a == i) {
+                    ledger.insert(day, year, *part, answer.clone());
+                }
+                ledger.save()?;
+            }
+        }
+
+        examples
+            .inputs
+            .get(index - 1)
+            .cloned()
+            .ok_or(FetchError::MissingExample {
+                index,
+                count: examples.inputs.len(),
+            })
+    }
+
+    /// Fetches the puzzle description as Markdown, refreshing the cached copy unless offline
+    pub fn fetch_puzzle(&mut self, day: u8, year: u16) -> Result<String, FetchError> {
+        let data_path =
+            PathBuf::from(&self.data_save_path).join(format!("{}/{:02}_puzzle.md", year, day));
 
-        let resp = client
-            .get(format!("{}{}", Self::BASE_ADDRESS, &path))
-            .header("Cookie", format!("session={}", self.cookie))
-            .send()
-            .expect("Expected to perform HTTP call");
+        if self.offline {
+            return std::fs::read_to_string(&data_path).map_err(|_| FetchError::Offline(data_path));
+        }
 
-        let text = resp.text().expect("expected text body");
+        unlock::check_unlocked(day, year, chrono::Utc::now())?;
 
-        let start = text.find("<pre><code>");
-        let end = text.find("</code></pre>");
+        let path = format!("/{year}/day/{day}");
+        debug!("Fetching puzzle from path: '{}'", path);
 
-        match (start, end) {
-            (Some(mut start), Some(end)) => {
-                start += "<pre><code>".len();
+        let markdown = html::puzzle_markdown(&self.get(day, year, &path)?);
+        if let Some(parent) = data_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&data_path, &markdown)?;
+
+        Ok(markdown)
+    }
 
-                // Testing to check if we need to decode html formatted input. Might be needed in somce cases?
-                return text[start..end]
-                    .lines()
-                    .map(|s: &str| html_escape::decode_html_entities(s).into_owned())
-                    .collect();
-                // return text[start..end].lines().map(|s| s.into()).collect();
+    /// Records example answers that were not on the page when the examples were cached, like the
+    /// part 2 answer that only shows up once part 1 is solved
+    pub fn refresh_examples(&mut self, day: u8, year: u16) -> anyhow::Result<()> {
+        let path = format!("/{year}/day/{day}");
+        debug!("Refreshing example answers from path: '{}'", path);
+
+        let examples = Examples::parse(&self.get(day, year, &path)?);
+        for (i, part, answer) in examples.answers {
+            let data_path = Self::input_path(&self.data_save_path, day, year, Some(i + 1), &None);
+            if !data_path.exists() {
+                write_input(&data_path, &examples.inputs[i])?;
+            }
+
+            let mut ledger = AnswerLedger::load_file(Self::answers_path(&data_path))?;
+            if ledger.get(day, year, part).is_none() {
+                info!("Recording the Part{} answer of example {}", part, i + 1);
+                ledger.insert(day, year, part, answer);
+                ledger.save()?;
             }
-            _ => vec![],
         }
+
+        Ok(())
+    }
+
+    /// Fetches a page, asking for a new session once if the current one is rejected
+    fn get(&mut self, day: u8, year: u16, path: &str) -> Result<String, FetchError> {
+        match self.get_once(day, year, path) {
+            Err(FetchError::NotLoggedIn) if std::env::var(Self::SESSION_ENV).is_err() => {
+                warn!("The session cookie was rejected");
+                self.cookie = Some(self.prompt_cookie()?);
+                self.get_once(day, year, path)
+            }
+            result => result,
+        }
+    }
+
+    /// Fetches a page, turning anything but a successful response into an error so it is never cached
+    fn get_once(&mut self, day: u8, year: u16, path: &str) -> Result<String, FetchError> {
+        let cookie = self.cookie()?;
+        let url = format!("{}{}", self.base_address, path);
+        let resp = self.client()?.get(&url, &cookie)?;
+
+        match resp.status() {
+            StatusCode::NOT_FOUND => return Err(FetchError::NotUnlocked { day, year }),
+            status => check_status(status)?,
+        }
+
+        let text = resp.text()?;
+        if text.contains("Please log in to get your puzzle input") {
+            return Err(FetchError::NotLoggedIn);
+        }
+
+        Ok(text)
+    }
+
+    pub fn submit_answer(
+        &mut self,
+        day: u8,
+        year: u16,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmitOutcome> {
+        if self.offline {
+            anyhow::bail!("Cannot submit answers while offline");
+        }
+
+        let path = format!("/{year}/day/{day}/answer");
+        debug!("Submitting answer to path: '{}'", path);
+
+        let cookie = self.cookie()?;
+        let url = format!("{}{}", self.base_address, path);
+        let form = [("level", part.to_string()), ("answer", answer.into())];
+        let resp = self.client()?.post_form(&url, &cookie, &form)?;
+        check_status(resp.status())?;
+
+        Ok(SubmitOutcome::parse(&resp.text()?))
     }
 
-    fn fetch_cookie(&mut self) {
-        let cookie_path = PathBuf::from(&self.data_save_path).join(Self::COOKIE_FILE);
-
-        match std::fs::read_to_string(&cookie_path) {
-            Ok(cookie) => self.cookie = cookie,
-            Err(_) => {
-                println!(
-                    "Could not find personal cookie or the previously entered cookie dident work."
-                );
-                println!(
-                    " Please enter the cookie when searching for input on {}",
-                    Self::BASE_ADDRESS
-                );
-                print!("cookie: ");
-                std::io::stdout().lock().flush().unwrap();
-                let stdin = std::io::stdin();
-                use std::io::BufRead;
-                let cookie = stdin.lock().lines().next().unwrap().unwrap();
-                self.cookie = cookie;
-
-                std::fs::create_dir_all(cookie_path.parent().unwrap())
-                    .expect("expected to create cookie");
-                std::fs::write(&cookie_path, &self.cookie)
-                    .expect("Expected to be able to write cookie");
+    /// Checks the session against a cheap page, which redirects to the login when logged out
+    pub fn verify_session(&mut self, cookie: &str) -> Result<bool, FetchError> {
+        let url = format!("{}/settings", self.base_address);
+        let resp = self.client()?.get(&url, cookie)?;
+
+        Ok(resp.status().is_success())
+    }
+
+    fn client(&mut self) -> Result<&AocClient, FetchError> {
+        if self.client.is_none() {
+            // The automation guidelines ask for a way to contact whoever runs the tool
+            let user_agent = self.user_agent.as_ref().ok_or(FetchError::NoUserAgent)?;
+            self.client = Some(AocClient::new(&self.data_save_path, user_agent)?);
+        }
+
+        Ok(self.client.as_ref().unwrap())
+    }
+
+    /// The session from `AOC_SESSION`, the cookie file or a prompt, in that order. Only a typed in
+    /// session is checked up front, the others are trusted until the server rejects them
+    fn cookie(&mut self) -> Result<String, FetchError> {
+        if let Some(cookie) = &self.cookie {
+            return Ok(cookie.clone());
+        }
+
+        let cookie = match std::env::var(Self::SESSION_ENV) {
+            Ok(session) => session.trim().into(),
+            Err(_) => match std::fs::read_to_string(self.cookie_path()) {
+                Ok(cookie) => cookie.trim().into(),
+                Err(_) => self.prompt_cookie()?,
+            },
+        };
+
+        self.cookie = Some(cookie.clone());
+        Ok(cookie)
+    }
+
+    /// Asks for a session cookie until the server accepts one. Never blocks when stdin is not a terminal
+    fn prompt_cookie(&mut self) -> Result<String, FetchError> {
+        if !std::io::stdin().is_terminal() {
+            return Err(FetchError::NotLoggedIn);
+        }
+
+        loop {
+            eprintln!(
+                "Could not find personal cookie or the previously entered cookie dident work."
+            );
+            eprintln!(
+                " Please enter the cookie when searching for input on {}",
+                self.base_address
+            );
+            eprint!("cookie: ");
+            std::io::stderr().flush()?;
+
+            let mut cookie = String::new();
+            std::io::stdin().read_line(&mut cookie)?;
+            let cookie = cookie.trim().to_string();
+
+            if cookie.is_empty() {
+                return Err(FetchError::NotLoggedIn);
             }
+
+            if self.verify_session(&cookie)? {
+                let cookie_path = self.cookie_path();
+                if let Some(parent) = cookie_path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(&cookie_path, &cookie)?;
+
+                return Ok(cookie);
+            }
+
+            eprintln!("The server did not accept that cookie.");
         }
     }
+
+    fn cookie_path(&self) -> PathBuf {
+        PathBuf::from(&self.data_save_path).join(&self.cookie_file)
+    }
+}
+
+/// A logged out session gets a bad request, or a redirect to the login since redirects are not followed
+fn check_status(status: StatusCode) -> Result<(), FetchError> {
+    match status {
+        StatusCode::BAD_REQUEST => Err(FetchError::NotLoggedIn),
+        status if status.is_redirection() => Err(FetchError::NotLoggedIn),
+        status if !status.is_success() => Err(FetchError::HttpStatus(status)),
+        _ => Ok(()),
+    }
+}
+
+fn write_input(data_path: &Path, input: &[String]) -> Result<(), FetchError> {
+    if let Some(parent) = data_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(data_path, input.join("\n"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{TempDir, stub_server};
+
+    #[test]
+    fn refuses_to_cache_errors() {
+        let body = "Please don't repeatedly request this endpoint before it unlocks!";
+        let (address, server) = stub_server(vec![("404 Not Found", body)]);
+
+        let data_path = TempDir::new("fetch");
+        let data_path_str = data_path.to_str().unwrap();
+
+        let mut input_fetcher = InputFetcher::new(data_path_str)
+            .with_session("abc")
+            .with_base_address(&address)
+            .with_user_agent("aoc-test");
+        let result = input_fetcher.fetch(25, 2024, None, &None);
+        server.join().unwrap();
+
+        let mut offline_fetcher = InputFetcher::new(data_path_str).with_offline(true);
+        let offline = offline_fetcher.fetch(25, 2024, None, &None);
+        let cached = InputFetcher::input_path(data_path_str, 25, 2024, None, &None).exists();
+
+        assert!(matches!(
+            result,
+            Err(FetchError::NotUnlocked {
+                day: 25,
+                year: 2024
+            })
+        ));
+        assert!(matches!(offline, Err(FetchError::Offline(_))));
+        assert!(!cached);
+    }
+
+    #[test]
+    fn verify_session() {
+        let (address, server) = stub_server(vec![
+            ("200 OK", ""),
+            ("302 Found\r\nLocation: /auth/login", ""),
+        ]);
+
+        let data_path = TempDir::new("session");
+        let mut input_fetcher = InputFetcher::new(data_path.to_str().unwrap())
+            .with_base_address(&address)
+            .with_user_agent("aoc-test");
+        let valid = input_fetcher.verify_session("abc").unwrap();
+        let expired = input_fetcher.verify_session("abc").unwrap();
+
+        let requests = server.join().unwrap();
+        let log = std::fs::read_to_string(data_path.join("requests.log")).unwrap();
+
+        assert!(valid);
+        assert!(!expired);
+        assert!(
+            requests
+                .iter()
+                .all(|request| request.to_lowercase().contains("user-agent: aoc-test"))
+        );
+        assert!(log.contains(&format!("GET {}/settings 200", address)));
+        assert!(log.contains(&format!("GET {}/settings 302", address)));
+    }
 }
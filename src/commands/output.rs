@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+/// A single result line. Part 0 is the parse step when benching
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration: Duration,
+    pub iterations: usize,
+    pub status: &'static str,
+}
+
+/// Writes records to stdout in the selected format. Without a format nothing is written,
+/// and results are only available through the log
+pub struct Output {
+    format: Option<OutputFormat>,
+    header_written: bool,
+}
+
+impl Output {
+    pub fn new(format: Option<OutputFormat>) -> Self {
+        Self {
+            format,
+            header_written: false,
+        }
+    }
+
+    pub fn emit(&mut self, record: &Record) {
+        let Some(format) = self.format else {
+            return;
+        };
+
+        if !self.header_written {
+            self.header_written = true;
+            match format {
+                OutputFormat::Table => println!(
+                    "{:<6}{:<5}{:<6}{:<30}{:<16}{:<12}Status",
+                    "Year", "Day", "Part", "Answer", "Duration", "Iterations"
+                ),
+                OutputFormat::Csv => {
+                    println!("year,day,part,answer,duration_ns,iterations,status")
+                }
+                OutputFormat::Json => (),
+            }
+        }
+
+        match format {
+            OutputFormat::Table => println!(
+                "{:<6}{:<5}{:<6}{:<30}{:<16}{:<12}{}",
+                record.year,
+                format!("{:02}", record.day),
+                record.part,
+                record.answer.as_deref().unwrap_or("-"),
+                format!("{:?}", record.duration),
+                record.iterations,
+                record.status
+            ),
+            OutputFormat::Csv => println!(
+                "{},{},{},{},{},{},{}",
+                record.year,
+                record.day,
+                record.part,
+                record.answer.as_deref().map(csv_escape).unwrap_or_default(),
+                record.duration.as_nanos(),
+                record.iterations,
+                record.status
+            ),
+            OutputFormat::Json => println!(
+                r#"{{"year":{},"day":{},"part":{},"answer":{},"duration_ns":{},"iterations":{},"status":"{}"}}"#,
+                record.year,
+                record.day,
+                record.part,
+                record
+                    .answer
+                    .as_deref()
+                    .map(json_escape)
+                    .unwrap_or("null".into()),
+                record.duration.as_nanos(),
+                record.iterations,
+                record.status
+            ),
+        }
+    }
+}
+
+fn csv_escape(s: &str) -> String {
+    match s.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", s.replace('"', "\"\"")),
+        false => s.into(),
+    }
+}
+
+fn json_escape(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes() {
+        assert_eq!(csv_escape("1,2"), "\"1,2\"");
+        assert_eq!(csv_escape("a\"b"), "\"a\"\"b\"");
+        assert_eq!(csv_escape("123"), "123");
+
+        assert_eq!(json_escape("co,de"), "\"co,de\"");
+        assert_eq!(json_escape("a\"b\\\n"), "\"a\\\"b\\\\\\n\"");
+    }
+}
@@ -1,31 +1,121 @@
+use std::{any::Any, time::Instant};
+
 use anyhow::Result;
 use tracing::info;
 
-use crate::events;
+use crate::{
+    commands::{
+        ledger::AnswerLedger,
+        output::{Output, Record},
+    },
+    events::{self, AnyAocDay, RunContext},
+};
+
+pub fn run_day(
+    day: u8,
+    year: u16,
+    input: Vec<String>,
+    ctx: &RunContext,
+    ledger: Option<&AnswerLedger>,
+    output: &mut Output,
+) -> Result<()> {
+    let mut aoc_day = events::get_day(day, year)?;
+    let input = aoc_day.parse_any(&input, ctx)?;
+    let (aoc_day, input) = (aoc_day.as_mut(), input.as_ref());
 
-pub fn run_day(day: u8, year: u16, input: Vec<String>, part: Option<u8>) -> Result<()> {
-    match part {
-        Some(1) => part1(day, year, &input),
-        Some(2) => part2(day, year, &input),
+    match ctx.part {
+        Some(1) => part1(day, year, aoc_day, input, ctx, ledger, output),
+        Some(2) => part2(day, year, aoc_day, input, ctx, ledger, output),
         _ => {
-            part1(day, year, &input)?;
-            part2(day, year, &input)
+            part1(day, year, aoc_day, input, ctx, ledger, output)?;
+            part2(day, year, aoc_day, input, ctx, ledger, output)
         }
     }
 }
 
-fn part1(day: u8, year: u16, input: &Vec<String>) -> Result<()> {
+pub fn confirm_day(
+    day: u8,
+    year: u16,
+    input: Vec<String>,
+    ctx: &RunContext,
+    ledger: &mut AnswerLedger,
+) -> Result<()> {
     let mut aoc_day = events::get_day(day, year)?;
+    let input = aoc_day.parse_any(&input, ctx)?;
+
+    if ctx.part.is_none_or(|p| p == 1) {
+        let part1 = aoc_day.run_part1_any(input.as_ref(), ctx)?;
+        ledger.record(day, year, 1, &part1)?;
+        info!("Part1: {} [RECORDED]", part1);
+    }
+
+    if ctx.part.is_none_or(|p| p == 2) {
+        let part2 = aoc_day.run_part2_any(input.as_ref(), ctx)?;
+        ledger.record(day, year, 2, &part2)?;
+        info!("Part2: {} [RECORDED]", part2);
+    }
 
-    let part1 = aoc_day.run_part1(input)?;
-    info!("Part1: {}", part1);
     Ok(())
 }
 
-fn part2(day: u8, year: u16, input: &Vec<String>) -> Result<()> {
-    let mut aoc_day = events::get_day(day, year)?;
+fn part1(
+    day: u8,
+    year: u16,
+    aoc_day: &mut dyn AnyAocDay,
+    input: &dyn Any,
+    ctx: &RunContext,
+    ledger: Option<&AnswerLedger>,
+    output: &mut Output,
+) -> Result<()> {
+    let start = Instant::now();
+    let part1 = aoc_day.run_part1_any(input, ctx)?;
+    let duration = start.elapsed();
+
+    let status = ledger.map(|ledger| ledger.check(day, year, 1, &part1));
+    match &status {
+        Some(status) => info!("Part1: {} {}", part1, status),
+        None => info!("Part1: {}", part1),
+    }
+
+    output.emit(&Record {
+        year,
+        day,
+        part: 1,
+        answer: Some(part1.to_string()),
+        duration,
+        iterations: 1,
+        status: status.map_or("OK", |s| s.label()),
+    });
+    Ok(())
+}
+
+fn part2(
+    day: u8,
+    year: u16,
+    aoc_day: &mut dyn AnyAocDay,
+    input: &dyn Any,
+    ctx: &RunContext,
+    ledger: Option<&AnswerLedger>,
+    output: &mut Output,
+) -> Result<()> {
+    let start = Instant::now();
+    let part2 = aoc_day.run_part2_any(input, ctx)?;
+    let duration = start.elapsed();
+
+    let status = ledger.map(|ledger| ledger.check(day, year, 2, &part2));
+    match &status {
+        Some(status) => info!("Part2: {} {}", part2, status),
+        None => info!("Part2: {}", part2),
+    }
 
-    let part2 = aoc_day.run_part2(input)?;
-    info!("Part2: {}", part2);
+    output.emit(&Record {
+        year,
+        day,
+        part: 2,
+        answer: Some(part2.to_string()),
+        duration,
+        iterations: 1,
+        status: status.map_or("OK", |s| s.label()),
+    });
     Ok(())
 }
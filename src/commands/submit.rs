@@ -0,0 +1,218 @@
+use std::{fmt::Display, time::Duration};
+
+use anyhow::Result;
+use tracing::{debug, info, warn};
+
+use crate::{
+    commands::{InputFetcher, html::strip_tags, ledger::AnswerLedger},
+    events::{self, RunContext},
+};
+
+#[derive(Debug, PartialEq)]
+pub enum SubmitOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    Wait(Duration),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl SubmitOutcome {
+    pub fn parse(page: &str) -> Self {
+        // The interesting part of the response is always inside the first <article>
+        let text = match (page.find("<article>"), page.find("</article>")) {
+            (Some(start), Some(end)) if start < end => &page[start + "<article>".len()..end],
+            _ => page,
+        };
+
+        if text.contains("That's the right answer") {
+            Self::Correct
+        } else if text.contains("your answer is too high") {
+            Self::TooHigh
+        } else if text.contains("your answer is too low") {
+            Self::TooLow
+        } else if text.contains("That's not the right answer") {
+            Self::Incorrect
+        } else if text.contains("You gave an answer too recently") {
+            Self::Wait(parse_wait(text).unwrap_or_default())
+        } else if text.contains("Did you already complete it?") {
+            Self::AlreadySolved
+        } else {
+            Self::Unknown(strip_tags(text).trim().into())
+        }
+    }
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "Correct!"),
+            SubmitOutcome::TooHigh => write!(f, "Wrong, answer is too high"),
+            SubmitOutcome::TooLow => write!(f, "Wrong, answer is too low"),
+            SubmitOutcome::Incorrect => write!(f, "Wrong answer"),
+            SubmitOutcome::Wait(duration) => write!(f, "Wait {:?} before submitting", duration),
+            SubmitOutcome::AlreadySolved => write!(f, "Already solved"),
+            SubmitOutcome::Unknown(text) => write!(f, "Unknown response: {}", text),
+        }
+    }
+}
+
+pub fn submit_day(
+    day: u8,
+    year: u16,
+    input: Vec<String>,
+    ctx: &RunContext,
+    input_fetcher: &mut InputFetcher,
+    ledger: &mut AnswerLedger,
+) -> Result<()> {
+    let Some(part) = ctx.part else {
+        anyhow::bail!("Submitting requires a part. Use --part 1 or --part 2");
+    };
+
+    let mut aoc_day = events::get_day(day, year)?;
+
+    let answer = match part {
+        1 => aoc_day.solve_part1(&input, ctx)?,
+        _ => aoc_day.solve_part2(&input, ctx)?,
+    };
+    info!("Submitting Part{}: {}", part, answer);
+
+    let outcome = input_fetcher.submit_answer(day, year, part, &answer.to_string())?;
+    info!("Part{}: {}", part, outcome);
+
+    if outcome == SubmitOutcome::Correct {
+        ledger.record(day, year, part, &answer)?;
+
+        // The part 2 example answer is only on the page once part 1 is solved
+        if part == 1
+            && let Err(err) = input_fetcher.refresh_examples(day, year)
+        {
+            warn!("Could not refresh the example answers: {}", err);
+        }
+    }
+
+    Ok(())
+}
+
+// Matches "You have 4m 32s left to wait" and "You have 35s left to wait"
+fn parse_wait(text: &str) -> Option<Duration> {
+    let end = text.find(" left to wait")?;
+    let start = text[..end].rfind("have ")? + "have ".len();
+    debug!("Parsing wait time: '{}'", &text[start..end]);
+
+    let mut seconds = 0;
+    for part in text[start..end].split_whitespace() {
+        let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 60 * 60,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        commands::error::FetchError,
+        test_utils::{TempDir, stub_server},
+    };
+
+    #[test]
+    fn parse_outcomes() {
+        let page = |s: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", s);
+
+        assert_eq!(
+            SubmitOutcome::parse(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            SubmitOutcome::Correct
+        );
+        assert_eq!(
+            SubmitOutcome::parse(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            SubmitOutcome::TooHigh
+        );
+        assert_eq!(
+            SubmitOutcome::parse(&page(
+                "That's not the right answer; your answer is too low. Please wait one minute."
+            )),
+            SubmitOutcome::TooLow
+        );
+        assert_eq!(
+            SubmitOutcome::parse(&page(
+                "That's not the right answer. Please wait one minute."
+            )),
+            SubmitOutcome::Incorrect
+        );
+        assert_eq!(
+            SubmitOutcome::parse(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait."
+            )),
+            SubmitOutcome::Wait(Duration::from_secs(4 * 60 + 32))
+        );
+        assert_eq!(
+            SubmitOutcome::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            SubmitOutcome::AlreadySolved
+        );
+        assert_eq!(
+            SubmitOutcome::parse(&page("Something <em>else</em>")),
+            SubmitOutcome::Unknown("Something else".into())
+        );
+    }
+
+    #[test]
+    fn submit_against_stub_server() {
+        let body = "<article><p>That's the right answer!</p></article>";
+        let (address, server) = stub_server(vec![("200 OK", body)]);
+
+        let data_path = TempDir::new("submit");
+
+        let mut input_fetcher = InputFetcher::new(data_path.to_str().unwrap())
+            .with_session("abc")
+            .with_base_address(&address)
+            .with_user_agent("aoc-test");
+        let outcome = input_fetcher.submit_answer(7, 2024, 2, "1234").unwrap();
+
+        let request = server.join().unwrap().remove(0);
+
+        assert_eq!(outcome, SubmitOutcome::Correct);
+        assert!(request.starts_with("POST /2024/day/7/answer"));
+        assert!(request.contains("session=abc"));
+        assert!(request.contains("level=2&answer=1234"));
+    }
+
+    #[test]
+    fn submit_logged_out() {
+        let (address, server) = stub_server(vec![
+            ("302 Found\r\nLocation: /auth/login", ""),
+            ("400 Bad Request", ""),
+        ]);
+
+        let data_path = TempDir::new("submit_logged_out");
+        let mut input_fetcher = InputFetcher::new(data_path.to_str().unwrap())
+            .with_session("expired")
+            .with_base_address(&address)
+            .with_user_agent("aoc-test");
+        let redirected = input_fetcher.submit_answer(7, 2024, 1, "1234");
+        let rejected = input_fetcher.submit_answer(7, 2024, 1, "1234");
+        server.join().unwrap();
+
+        for result in [redirected, rejected] {
+            assert!(matches!(
+                result.unwrap_err().downcast(),
+                Ok(FetchError::NotLoggedIn)
+            ));
+        }
+    }
+}
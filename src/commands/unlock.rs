@@ -0,0 +1,85 @@
+use std::{io::Write, time::Duration};
+
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+
+use crate::commands::error::FetchError;
+
+// Puzzles unlock at midnight EST, which AoC uses all through December
+const UNLOCK_OFFSET_SECONDS: i32 = -5 * 3600;
+
+/// Events from 2025 onwards only run for 12 days
+pub fn days_in_event(year: u16) -> u8 {
+    match year >= 2025 {
+        true => 12,
+        false => 25,
+    }
+}
+
+pub fn unlock_time(day: u8, year: u16) -> Option<DateTime<Utc>> {
+    if day == 0 || day > days_in_event(year) {
+        return None;
+    }
+
+    FixedOffset::east_opt(UNLOCK_OFFSET_SECONDS)?
+        .with_ymd_and_hms(year as i32, 12, day as u32, 0, 0, 0)
+        .single()
+        .map(|time| time.to_utc())
+}
+
+pub fn check_unlocked(day: u8, year: u16, now: DateTime<Utc>) -> Result<(), FetchError> {
+    match unlock_time(day, year) {
+        None => Err(FetchError::NoSuchDay { day, year }),
+        Some(unlock) if unlock > now => Err(FetchError::NotUnlocked { day, year }),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Sleeps until the day unlocks, printing a countdown to stderr
+pub fn wait_for_unlock(day: u8, year: u16) -> Result<(), FetchError> {
+    let unlock = unlock_time(day, year).ok_or(FetchError::NoSuchDay { day, year })?;
+
+    while let Ok(remaining) = (unlock - Utc::now()).to_std() {
+        let seconds = remaining.as_secs();
+        eprint!(
+            "\rDay {} Year {} unlocks in {:02}:{:02}:{:02} ",
+            day,
+            year,
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        );
+        std::io::stderr().flush()?;
+
+        std::thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+    eprintln!();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schedule() {
+        let unlock = unlock_time(1, 2024).unwrap();
+        assert_eq!(unlock, Utc.with_ymd_and_hms(2024, 12, 1, 5, 0, 0).unwrap());
+
+        assert!(unlock_time(25, 2024).is_some());
+        assert!(unlock_time(13, 2025).is_none());
+        assert!(unlock_time(0, 2025).is_none());
+
+        let before = Utc.with_ymd_and_hms(2025, 12, 3, 4, 59, 59).unwrap();
+        let after = Utc.with_ymd_and_hms(2025, 12, 3, 5, 0, 0).unwrap();
+        assert!(matches!(
+            check_unlocked(3, 2025, before),
+            Err(FetchError::NotUnlocked { .. })
+        ));
+        assert!(check_unlocked(3, 2025, after).is_ok());
+        assert!(matches!(
+            check_unlocked(20, 2025, after),
+            Err(FetchError::NoSuchDay { .. })
+        ));
+    }
+}
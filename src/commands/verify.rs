@@ -0,0 +1,241 @@
+use std::{
+    any::Any,
+    fmt::Display,
+    panic::{AssertUnwindSafe, catch_unwind},
+};
+
+use anyhow::{Context, Result};
+use tracing::{debug, info};
+
+use crate::{
+    commands::{
+        InputFetcher,
+        ledger::{AnswerLedger, LedgerStatus},
+    },
+    config::Config,
+    events::{self, RunContext, Solution},
+    utils::AoCResult,
+};
+
+enum PartStatus {
+    Checked(LedgerStatus),
+    Error(String),
+    Panic(String),
+}
+
+struct DayReport {
+    day: u8,
+    year: u16,
+    parts: Option<[PartStatus; 2]>,
+}
+
+pub fn verify_all(data_save_path: &str, ledger: &AnswerLedger) -> Result<()> {
+    let mut reports = vec![];
+
+    // Days panicking is expected here, so keep the default hook from spamming the table
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+
+    for solution in events::solutions() {
+        reports.push(verify_day(data_save_path, ledger, solution));
+    }
+
+    std::panic::set_hook(hook);
+
+    println!("{:<6}{:<5}{:<40}{:<40}", "Year", "Day", "Part1", "Part2");
+    for report in &reports {
+        match &report.parts {
+            Some([part1, part2]) => println!(
+                "{:<6}{:<5}{:<40.38}{:<40.38}",
+                report.year,
+                format!("{:02}", report.day),
+                part1.to_string(),
+                part2.to_string()
+            ),
+            None => println!(
+                "{:<6}{:<5}[NO INPUT]",
+                report.year,
+                format!("{:02}", report.day)
+            ),
+        }
+    }
+
+    for report in &reports {
+        for (part, status) in report.parts.iter().flatten().enumerate() {
+            if !matches!(
+                status,
+                PartStatus::Checked(LedgerStatus::Pass | LedgerStatus::New)
+            ) {
+                println!(
+                    "{} Day {:02} Part{}: {}",
+                    report.year,
+                    report.day,
+                    part + 1,
+                    status
+                );
+            }
+        }
+    }
+
+    let parts = reports.iter().flat_map(|r| r.parts.iter().flatten());
+    let (mut passed, mut failed, mut new, mut errors, mut panics) = (0, 0, 0, 0, 0);
+    for status in parts {
+        match status {
+            PartStatus::Checked(LedgerStatus::Pass) => passed += 1,
+            PartStatus::Checked(LedgerStatus::Fail(_)) => failed += 1,
+            PartStatus::Checked(LedgerStatus::New) => new += 1,
+            PartStatus::Error(_) => errors += 1,
+            PartStatus::Panic(_) => panics += 1,
+        }
+    }
+    let missing = reports.iter().filter(|r| r.parts.is_none()).count();
+
+    info!(
+        "Verified {} days: {} passed, {} failed, {} new, {} errors, {} panics, {} without input",
+        reports.len(),
+        passed,
+        failed,
+        new,
+        errors,
+        panics,
+        missing
+    );
+
+    if failed + errors + panics > 0 {
+        anyhow::bail!("{} parts did not verify", failed + errors + panics);
+    }
+
+    Ok(())
+}
+
+/// Checks a day against every cached example that has known answers. Meant for the generated day
+/// tests, which are skipped with a note while no example answers are cached
+pub fn verify_examples(day: u8, year: u16) -> Result<()> {
+    let data_save_path = Config::load()?.input_folder;
+    let solution =
+        events::find_solution(day, year).with_context(|| format!("No day {} {}", day, year))?;
+    let mut checked = 0;
+
+    for index in 1.. {
+        let path = InputFetcher::input_path(&data_save_path, day, year, Some(index), &None);
+        let Ok(input) = std::fs::read_to_string(&path) else {
+            break;
+        };
+        let input: Vec<String> = input.lines().map(|s| s.into()).collect();
+        let ledger = AnswerLedger::load_file(InputFetcher::answers_path(&path))?;
+        if (1..=2).all(|part| ledger.get(day, year, part).is_none()) {
+            continue;
+        }
+
+        let ctx = RunContext::test();
+        let mut aoc_day = solution.create();
+        let parsed = aoc_day.parse_any(&input, &ctx)?;
+
+        for part in 1..=2 {
+            if ledger.get(day, year, part).is_none() {
+                continue;
+            }
+
+            let result = match part {
+                1 => aoc_day.run_part1_any(parsed.as_ref(), &ctx)?,
+                _ => aoc_day.run_part2_any(parsed.as_ref(), &ctx)?,
+            };
+
+            if let LedgerStatus::Fail(expected) = ledger.check(day, year, part, &result) {
+                anyhow::bail!(
+                    "Example {} Part{}: got '{}', expected '{}'",
+                    index,
+                    part,
+                    result,
+                    expected
+                );
+            }
+            checked += 1;
+        }
+    }
+
+    if checked == 0 {
+        eprintln!(
+            "Skipped, no example answers cached for Day {} Year {}, fetch them with `run {} -y {} --test`",
+            day, year, day, year
+        );
+    }
+
+    Ok(())
+}
+
+fn verify_day(data_save_path: &str, ledger: &AnswerLedger, solution: &Solution) -> DayReport {
+    let (day, year) = (solution.day, solution.year);
+    let path = InputFetcher::input_path(data_save_path, day, year, None, &None);
+    debug!("Verifying Day {} Year {} with {:?}", day, year, path);
+
+    let parts = std::fs::read_to_string(&path).ok().map(|input| {
+        let input: Vec<String> = input.lines().map(|s| s.into()).collect();
+        let ctx = RunContext::live();
+        let mut aoc_day = solution.create();
+
+        // A failed parse fails both parts the same way
+        let parsed = match catch_unwind(AssertUnwindSafe(|| aoc_day.parse_any(&input, &ctx))) {
+            Ok(Ok(parsed)) => parsed,
+            Ok(Err(err)) => {
+                let message = err.to_string();
+                return [
+                    PartStatus::Error(message.clone()),
+                    PartStatus::Error(message),
+                ];
+            }
+            Err(payload) => {
+                let message = panic_message(payload);
+                return [
+                    PartStatus::Panic(message.clone()),
+                    PartStatus::Panic(message),
+                ];
+            }
+        };
+
+        [
+            verify_part(ledger, day, year, 1, || {
+                aoc_day.run_part1_any(parsed.as_ref(), &ctx)
+            }),
+            verify_part(ledger, day, year, 2, || {
+                aoc_day.run_part2_any(parsed.as_ref(), &ctx)
+            }),
+        ]
+    });
+
+    DayReport { day, year, parts }
+}
+
+fn verify_part<F: FnOnce() -> Result<AoCResult>>(
+    ledger: &AnswerLedger,
+    day: u8,
+    year: u16,
+    part: u8,
+    run: F,
+) -> PartStatus {
+    match catch_unwind(AssertUnwindSafe(run)) {
+        Ok(Ok(result)) => PartStatus::Checked(ledger.check(day, year, part, &result)),
+        Ok(Err(err)) => PartStatus::Error(err.to_string()),
+        Err(payload) => PartStatus::Panic(panic_message(payload)),
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".into(),
+        },
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartStatus::Checked(status) => write!(f, "{}", status),
+            PartStatus::Error(err) => write!(f, "[ERROR] {}", err),
+            PartStatus::Panic(message) => write!(f, "[PANIC] {}", message),
+        }
+    }
+}
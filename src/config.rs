@@ -0,0 +1,106 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::commands::{InputFetcher, bench::BenchCutoffs, output::OutputFormat};
+
+/// Project settings from `aoc.toml`, found in the current directory or any parent.
+/// Relative paths in the file are relative to the file itself
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub days_folder: String,
+    pub input_folder: String,
+    pub templates_folder: String,
+    pub base_address: String,
+    pub cookie_file: String,
+    pub user_agent: Option<String>,
+    pub year: Option<u16>,
+    pub part: Option<u8>,
+    pub format: Option<OutputFormat>,
+    pub bench: BenchCutoffs,
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+impl Config {
+    const CONFIG_FILE: &'static str = "aoc.toml";
+
+    pub fn load() -> Result<Self> {
+        let cwd = std::env::current_dir()?;
+        match cwd
+            .ancestors()
+            .map(|dir| dir.join(Self::CONFIG_FILE))
+            .find(|path| path.is_file())
+        {
+            Some(path) => Self::load_file(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    fn load_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let mut config: Config =
+            toml::from_str(&content).with_context(|| format!("Malformed config {:?}", path))?;
+
+        if config.part.is_some_and(|part| !(1..=2).contains(&part)) {
+            anyhow::bail!("Malformed config {:?}: part must be 1 or 2", path);
+        }
+
+        let root = path.parent().unwrap_or(Path::new("."));
+        config.days_folder = root.join(&config.days_folder).to_string_lossy().into();
+        config.input_folder = root.join(&config.input_folder).to_string_lossy().into();
+        config.templates_folder = root.join(&config.templates_folder).to_string_lossy().into();
+        config.path = Some(path.into());
+
+        Ok(config)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            days_folder: "./src/events".into(),
+            input_folder: "./inputs".into(),
+            templates_folder: "./templates".into(),
+            base_address: InputFetcher::BASE_ADDRESS.into(),
+            cookie_file: InputFetcher::COOKIE_FILE.into(),
+            user_agent: None,
+            year: None,
+            part: None,
+            format: None,
+            bench: BenchCutoffs::default(),
+            path: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TempDir;
+
+    #[test]
+    fn load_config() {
+        let dir = TempDir::new("config");
+        let path = dir.join(Config::CONFIG_FILE);
+        std::fs::write(
+            &path,
+            "input_folder = \"data\"\nyear = 2023\nformat = \"csv\"\n\n[bench]\niterations = 10\n",
+        )
+        .unwrap();
+
+        let config = Config::load_file(&path).unwrap();
+
+        assert_eq!(config.input_folder, dir.join("data").to_string_lossy());
+        assert_eq!(
+            config.days_folder,
+            dir.join("./src/events").to_string_lossy()
+        );
+        assert_eq!(config.year, Some(2023));
+        assert_eq!(config.format, Some(OutputFormat::Csv));
+        assert_eq!(config.bench.iterations, 10);
+        assert_eq!(config.bench.time_ms, BenchCutoffs::default().time_ms);
+    }
+}
@@ -1,24 +1,26 @@
-use std::{io::Write, path::PathBuf};
+use std::{fmt::Write as _, io::Write, path::PathBuf};
 
 use anyhow::Result;
-use tracing::debug;
+use tracing::{debug, info, warn};
 
 pub struct DayGenerator {
     file_path: String,
+    templates_path: String,
 }
 
 impl DayGenerator {
-    pub fn new(file_path: String) -> Self {
-        Self { file_path }
+    pub fn new(file_path: String, templates_path: String) -> Self {
+        Self {
+            file_path,
+            templates_path,
+        }
     }
 
-    pub fn generate_day(&self, day: u8, year: u16) -> Result<()> {
+    pub fn generate_day(&self, day: u8, year: u16, title: &str) -> Result<()> {
         let year_path = PathBuf::from(&self.file_path).join(format!("year_{}", year));
 
         std::fs::create_dir_all(&year_path)?;
 
-        let mod_year_path = year_path.join("mod.rs");
-
         let day_path = year_path.join(format!("day_{:02}.rs", day));
 
         let mut day_file = std::fs::OpenOptions::new()
@@ -26,126 +28,167 @@ impl DayGenerator {
             .write(true)
             .open(day_path)?;
 
-        let year_mod_file = std::fs::OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(mod_year_path)?;
+        let day_template = self.day_template(year)?;
 
-        let mut task_mod_file = std::fs::OpenOptions::new()
-            .create(true)
-            .write(true)
-            .truncate(true)
-            .open(PathBuf::from(&self.file_path).join("mod.rs"))?;
+        let day_formatted = day_template
+            .replace("$YEAR$", &year.to_string())
+            .replace("$DAY$", &day.to_string())
+            .replace("$TITLE$", &title.escape_default().to_string());
 
-        writeln!(&year_mod_file, "pub mod day_{:02};", day)?;
+        day_file.write_all(day_formatted.as_bytes())?;
+        day_file.flush()?;
 
-        let mod_template = include_str!("./templates/mod_template.txt");
-        let day_template = include_str!("./templates/day_template.txt");
+        self.write_year_mod(year)?;
+        self.write_mod()
+    }
 
-        let mod_day_string = self.get_mod_string();
-        let match_day_string = self.get_match_string();
+    /// Rebuilds `mod.rs` and every `year_*/mod.rs` from the day files on disk
+    pub fn regenerate(&self) -> Result<()> {
+        for year in self.years()? {
+            self.write_year_mod(year)?;
+        }
+        self.write_mod()?;
 
-        let composite_formatted = mod_template
-            .replace("$MOD_DAYS$", &mod_day_string)
-            .replace("$MATCH_DAYS$", &match_day_string);
+        info!("Regenerated modules in {}", self.file_path);
+        Ok(())
+    }
 
-        day_file.write_all(day_template.as_bytes())?;
-        day_file.flush()?;
+    /// Picks `day_template_{year}.txt` or `day_template.txt` from the templates folder, falling
+    /// back to the built-in template for the year
+    fn day_template(&self, year: u16) -> Result<String> {
+        let templates_path = PathBuf::from(&self.templates_path);
+
+        for name in [
+            format!("day_template_{}.txt", year),
+            "day_template.txt".into(),
+        ] {
+            let path = templates_path.join(name);
+            if path.is_file() {
+                debug!("Using day template {:?}", path);
+                return Ok(std::fs::read_to_string(path)?);
+            }
+        }
 
-        task_mod_file.write_all(composite_formatted.as_bytes())?;
-        task_mod_file.flush()?;
-        Ok(())
+        Ok(match year {
+            2019 => include_str!("./templates/day_template_2019.txt"),
+            _ => include_str!("./templates/day_template.txt"),
+        }
+        .into())
     }
 
     pub fn get_current_day(&self, year: u16) -> Result<u8> {
-        let mut next: u8 = 0;
+        Ok(self.days(year)?.last().copied().unwrap_or(0))
+    }
+
+    fn write_year_mod(&self, year: u16) -> Result<()> {
+        let mut mod_string = String::new();
+        for day in self.days(year)? {
+            writeln!(mod_string, "pub mod day_{:02};", day)?;
+        }
 
         let year_path = PathBuf::from(&self.file_path).join(format!("year_{}", year));
+        std::fs::write(year_path.join("mod.rs"), mod_string)?;
+        Ok(())
+    }
 
-        let dir_entry = match std::fs::read_dir(year_path) {
-            Ok(dir) => dir,
-            Err(_) => return Ok(next),
-        };
+    fn write_mod(&self) -> Result<()> {
+        let mut mod_string = String::new();
+        for year in self.years()? {
+            writeln!(mod_string, "mod year_{};", year)?;
+        }
 
-        for entry in dir_entry
-            .into_iter()
-            .filter_map(|p| p.ok())
-            .filter(|p| !p.file_name().eq_ignore_ascii_case("mod.rs"))
-        {
-            // Should not be possible
-            if entry.file_type().unwrap().is_dir() {
-                continue;
-            }
+        let mod_template = include_str!("./templates/mod_template.txt");
+        std::fs::write(
+            PathBuf::from(&self.file_path).join("mod.rs"),
+            mod_template.replace("$MOD_DAYS$", &mod_string),
+        )?;
+        Ok(())
+    }
 
-            let date: u8 =
-                entry.file_name().to_str().unwrap().to_ascii_lowercase()[4..6].parse()?;
+    /// The days with a `day_XX.rs` file in the year folder, sorted
+    fn days(&self, year: u16) -> Result<Vec<u8>> {
+        let year_path = PathBuf::from(&self.file_path).join(format!("year_{}", year));
+
+        let dir_entry = match std::fs::read_dir(&year_path) {
+            Ok(dir) => dir,
+            Err(_) => return Ok(Vec::new()),
+        };
 
-            if date > next {
-                next = date;
+        let mut days = Vec::new();
+        for entry in dir_entry {
+            let name = entry?.file_name();
+            let name = name.to_string_lossy();
+            match parse_name(&name, "day_", ".rs", 2) {
+                Some(day) => days.push(day.try_into()?),
+                None if name == "mod.rs" => (),
+                None => warn!("Skipping unexpected file {:?}", year_path.join(&*name)),
             }
         }
 
-        Ok(next)
+        days.sort();
+        Ok(days)
     }
 
-    fn get_mod_string(&self) -> String {
-        use std::fmt::Write;
-        let dir_entry = std::fs::read_dir(&self.file_path).expect("Expected a year entry here");
-
-        let mut mod_string = String::new();
+    /// The years with a `year_XXXX` folder, sorted
+    fn years(&self) -> Result<Vec<u16>> {
+        let mut years = Vec::new();
+        for entry in std::fs::read_dir(&self.file_path)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
 
-        for entry in dir_entry
-            .into_iter()
-            .filter(|p| p.as_ref().unwrap().file_type().unwrap().is_dir())
-            .map(|p| p.unwrap())
-        {
-            let file_name = entry.file_name();
-            let name = file_name.to_str().unwrap();
-            writeln!(mod_string, "mod {};", name).expect("Expected to be able to format string")
+            let name = entry.file_name();
+            match parse_name(&name.to_string_lossy(), "year_", "", 4) {
+                Some(year) => years.push(year.try_into()?),
+                None => warn!("Skipping unexpected folder {:?}", entry.path()),
+            }
         }
 
-        mod_string
+        years.sort();
+        Ok(years)
     }
+}
 
-    fn get_match_string(&self) -> String {
-        use std::fmt::Write;
-        let dir_entry = std::fs::read_dir(&self.file_path).expect("Expected a year entry here");
-
-        let mut mod_string = String::new();
+/// Parses names like `day_05.rs`, which must have exactly `digits` digits between the affixes
+fn parse_name(name: &str, prefix: &str, suffix: &str, digits: usize) -> Option<u32> {
+    let number = name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+    match number.len() == digits && number.bytes().all(|b| b.is_ascii_digit()) {
+        true => number.parse().ok(),
+        false => None,
+    }
+}
 
-        for year_entry in dir_entry
-            .into_iter()
-            .filter(|p| p.as_ref().unwrap().file_type().unwrap().is_dir())
-            .filter_map(|p| p.ok())
-        {
-            let year: u16 = year_entry
-                .file_name()
-                .to_str()
-                .unwrap()
-                .to_ascii_lowercase()[5..]
-                .parse()
-                .expect("Expected year to be parsable");
-            debug!("year: {}", year);
-
-            for day_entry_str in std::fs::read_dir(year_entry.path())
-                .expect("Expected a year entry here")
-                .filter_map(|p| p.ok())
-                .filter(|p| !p.file_name().eq_ignore_ascii_case("mod.rs"))
-            {
-                let file_name = day_entry_str.file_name();
-                debug!("file_name: {:?}", &file_name);
-                let day: u8 = file_name.to_str().unwrap().to_ascii_lowercase()[4..6]
-                    .parse()
-                    .expect("expected to parse date");
-                writeln!(
-                    mod_string,
-                    "({}, {}) => Ok(Box::new(year_{}::day_{:02}::Day::new())),",
-                    day, year, year, day
-                )
-                .expect("Expected to be able to format string")
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TempDir;
+
+    #[test]
+    fn regenerate_with_stray_files() {
+        let dir = TempDir::new("generator");
+        let year_path = dir.join("year_2023");
+        std::fs::create_dir_all(&year_path).unwrap();
+        std::fs::create_dir_all(dir.join("scratch")).unwrap();
+        for file in [
+            "day_10.rs",
+            "day_02.rs",
+            ".day_02.rs.swp",
+            "helpers.rs",
+            "README",
+        ] {
+            std::fs::write(year_path.join(file), "").unwrap();
         }
 
-        mod_string
+        let generator = DayGenerator::new(dir.to_string_lossy().into(), String::new());
+        assert_eq!(generator.get_current_day(2023).unwrap(), 10);
+        assert_eq!(generator.get_current_day(2024).unwrap(), 0);
+
+        generator.regenerate().unwrap();
+        let year_mod = std::fs::read_to_string(year_path.join("mod.rs")).unwrap();
+        let events_mod = std::fs::read_to_string(dir.join("mod.rs")).unwrap();
+
+        assert_eq!(year_mod, "pub mod day_02;\npub mod day_10;\n");
+        assert!(events_mod.starts_with("mod year_2023;\n\nmod registry;"));
     }
 }
@@ -0,0 +1,192 @@
+use std::{
+    any::Any,
+    collections::{BTreeMap, BTreeSet},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+
+use crate::utils::AoCResult;
+
+/// How a day is being run, so days can pick parameters that differ between the example and live input
+#[derive(Debug, Clone, Default)]
+pub struct RunContext {
+    pub test: bool,
+    pub input: Option<String>,
+    pub verbose: bool,
+    pub part: Option<u8>,
+    /// Overrides for the parameters of a day, from `--param name=value`
+    pub params: BTreeMap<String, String>,
+    /// Names of the parameters the day asked for, shared between clones
+    pub(crate) used_params: Arc<Mutex<BTreeSet<String>>>,
+}
+
+impl RunContext {
+    pub fn live() -> Self {
+        Self::default()
+    }
+
+    pub fn test() -> Self {
+        Self {
+            test: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn use_param(&self, name: &str) {
+        self.used_params.lock().unwrap().insert(name.into());
+    }
+
+    /// Overrides that no parameter of the day asked for, most likely typos
+    pub fn unused_params(&self) -> Vec<&str> {
+        let used = self.used_params.lock().unwrap();
+        self.params
+            .keys()
+            .filter(|name| !used.contains(*name))
+            .map(|name| name.as_str())
+            .collect()
+    }
+}
+
+/// A day whose input is parsed once and shared between both parts
+pub trait AocDay {
+    type Input: 'static;
+
+    fn parse(&mut self, input: &[String], ctx: &RunContext) -> Result<Self::Input>;
+    fn run_part1(&mut self, input: &Self::Input, ctx: &RunContext) -> Result<AoCResult>;
+    fn run_part2(&mut self, input: &Self::Input, ctx: &RunContext) -> Result<AoCResult>;
+}
+
+/// A day working directly on the input lines, without needing the run context.
+/// Implements `AocDay` with the lines as its input
+pub trait UntypedAocDay {
+    fn run_part1(&mut self, input: &[String]) -> Result<AoCResult>;
+    fn run_part2(&mut self, input: &[String]) -> Result<AoCResult>;
+}
+
+impl<D: UntypedAocDay> AocDay for D {
+    type Input = Vec<String>;
+
+    fn parse(&mut self, input: &[String], _: &RunContext) -> Result<Self::Input> {
+        Ok(input.to_vec())
+    }
+
+    fn run_part1(&mut self, input: &Self::Input, _: &RunContext) -> Result<AoCResult> {
+        UntypedAocDay::run_part1(self, input)
+    }
+
+    fn run_part2(&mut self, input: &Self::Input, _: &RunContext) -> Result<AoCResult> {
+        UntypedAocDay::run_part2(self, input)
+    }
+}
+
+/// Object safe version of `AocDay`, where the parsed input is type erased
+pub trait AnyAocDay {
+    fn parse_any(&mut self, input: &[String], ctx: &RunContext) -> Result<Box<dyn Any>>;
+    fn run_part1_any(&mut self, input: &dyn Any, ctx: &RunContext) -> Result<AoCResult>;
+    fn run_part2_any(&mut self, input: &dyn Any, ctx: &RunContext) -> Result<AoCResult>;
+
+    fn solve_part1(&mut self, input: &[String], ctx: &RunContext) -> Result<AoCResult> {
+        let input = self.parse_any(input, ctx)?;
+        self.run_part1_any(input.as_ref(), ctx)
+    }
+
+    fn solve_part2(&mut self, input: &[String], ctx: &RunContext) -> Result<AoCResult> {
+        let input = self.parse_any(input, ctx)?;
+        self.run_part2_any(input.as_ref(), ctx)
+    }
+}
+
+impl<D: AocDay> AnyAocDay for D {
+    fn parse_any(&mut self, input: &[String], ctx: &RunContext) -> Result<Box<dyn Any>> {
+        Ok(Box::new(AocDay::parse(self, input, ctx)?))
+    }
+
+    fn run_part1_any(&mut self, input: &dyn Any, ctx: &RunContext) -> Result<AoCResult> {
+        AocDay::run_part1(self, downcast_input::<D>(input)?, ctx)
+    }
+
+    fn run_part2_any(&mut self, input: &dyn Any, ctx: &RunContext) -> Result<AoCResult> {
+        AocDay::run_part2(self, downcast_input::<D>(input)?, ctx)
+    }
+}
+
+fn downcast_input<D: AocDay>(input: &dyn Any) -> Result<&D::Input> {
+    match input.downcast_ref() {
+        Some(input) => Ok(input),
+        None => anyhow::bail!("Received input parsed by a different day"),
+    }
+}
+
+/// A solution registered by its day file through `inventory::submit!`
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    factory: fn() -> Box<dyn AnyAocDay>,
+}
+
+impl Solution {
+    pub const fn new(
+        year: u16,
+        day: u8,
+        title: &'static str,
+        factory: fn() -> Box<dyn AnyAocDay>,
+    ) -> Self {
+        Self {
+            year,
+            day,
+            title,
+            factory,
+        }
+    }
+
+    pub fn create(&self) -> Box<dyn AnyAocDay> {
+        (self.factory)()
+    }
+}
+
+inventory::collect!(Solution);
+
+/// All registered solutions, sorted by year and day
+pub fn solutions() -> Vec<&'static Solution> {
+    let mut solutions: Vec<_> = inventory::iter::<Solution>.into_iter().collect();
+    solutions.sort_by_key(|s| (s.year, s.day));
+    solutions
+}
+
+pub fn find_solution(day: u8, year: u16) -> Option<&'static Solution> {
+    inventory::iter::<Solution>
+        .into_iter()
+        .find(|s| s.day == day && s.year == year)
+}
+
+pub fn has_day(day: u8, year: u16) -> bool {
+    find_solution(day, year).is_some()
+}
+
+pub fn get_day(day: u8, year: u16) -> Result<Box<dyn AnyAocDay>> {
+    match find_solution(day, year) {
+        Some(solution) => Ok(solution.create()),
+        None => anyhow::bail!("Received invalid day: {}", day),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_duplicate_registrations() {
+        let solutions = solutions();
+
+        for pair in solutions.windows(2) {
+            assert_ne!(
+                (pair[0].year, pair[0].day),
+                (pair[1].year, pair[1].day),
+                "{} is registered twice",
+                pair[0].title
+            );
+        }
+    }
+}
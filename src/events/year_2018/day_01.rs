@@ -4,7 +4,7 @@ use anyhow::Result;
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -14,7 +14,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2018, 1, "Chronal Calibration", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let sum = input
             .iter()
@@ -4,7 +4,7 @@ use crate::utils::*;
 
 use self::slice_utils::GrpBy;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -14,7 +14,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2018, 2, "Inventory Management System", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let mut has_2 = 0;
         let mut has_3 = 0;
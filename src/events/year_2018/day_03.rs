@@ -6,7 +6,7 @@ use slice_utils::Single;
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -16,7 +16,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2018, 3, "No Matter How You Slice It", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         const DIMENSION: usize = 1000;
         let rectangles: Vec<_> = input.iter().map(|s| Rectangle::from(s.as_str())).collect();
@@ -6,7 +6,7 @@ use tracing::debug;
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -16,7 +16,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2018, 4, "Repose Record", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let mut events: Vec<Event> = input.into_iter().map(|s| s.parse().unwrap()).collect();
         events.sort_by(|a, b| a.time.cmp(&b.time));
@@ -3,7 +3,7 @@ use slice_utils::Single;
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -13,7 +13,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2018, 5, "Alchemical Reduction", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let polymers: Vec<_> = input
             .iter()
@@ -2,11 +2,11 @@ use std::{collections::HashSet, fmt::Display, usize};
 
 use anyhow::Result;
 use grid::Grid2D;
-use variable::{variable, Variable};
+use variable::{Variable, param};
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{AocDay, RunContext, Solution};
 
 pub struct Day {}
 
@@ -16,8 +16,18 @@ impl Day {
     }
 }
 
+inventory::submit! {
+    Solution::new(2018, 6, "Chronal Coordinates", || Box::new(Day::new()))
+}
+
 impl AocDay for Day {
-    fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
+    type Input = Vec<String>;
+
+    fn parse(&mut self, input: &[String], _: &RunContext) -> Result<Self::Input> {
+        Ok(input.to_vec())
+    }
+
+    fn run_part1(&mut self, input: &Self::Input, _: &RunContext) -> Result<AoCResult> {
         let points: Vec<_> = input
             .iter()
             .map(|s| {
@@ -82,8 +92,9 @@ impl AocDay for Day {
         Ok(largest_area.into())
     }
 
-    fn run_part2(&mut self, input: &[String]) -> Result<AoCResult> {
-        const DIST: Variable<usize> = variable(32, 10000);
+    fn run_part2(&mut self, input: &Self::Input, ctx: &RunContext) -> Result<AoCResult> {
+        const DIST: Variable<usize> = param("max_distance", 32, 10000);
+        let max_distance = DIST.get(ctx)?;
 
         let points: Vec<_> = input
             .iter()
@@ -100,11 +111,7 @@ impl AocDay for Day {
 
             let dist: usize = points.iter().map(|p| p.manhattan_distance(&source)).sum();
 
-            if dist < *DIST {
-                1
-            } else {
-                0
-            }
+            if dist < max_distance { 1 } else { 0 }
         });
 
         let count = grid.find_all(|&x| x == 1).len();
@@ -3,7 +3,7 @@ use anyhow::Result;
 use crate::utils::*;
 use crate::vm::VM;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -13,7 +13,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2019, 2, "1202 Program Alarm", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let mut instruction_set: Vec<i64> =
             input[0].split(',').filter_map(|p| p.parse().ok()).collect();
@@ -1,6 +1,6 @@
 use anyhow::Result;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 use crate::utils::*;
 use crate::vm::VM;
 
@@ -12,7 +12,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2019, 5, "Sunny with a Chance of Asteroids", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let instruction_set: Vec<i64> =
             input[0].split(',').filter_map(|p| p.parse().ok()).collect();
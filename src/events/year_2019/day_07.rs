@@ -6,7 +6,7 @@ use crate::{
     vm::{self, VM},
 };
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -16,7 +16,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2019, 7, "Amplification Circuit", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let instruction_set: Vec<i64> =
             input[0].split(',').filter_map(|p| p.parse().ok()).collect();
@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use crate::{utils::*, vm::VM};
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -12,7 +12,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2019, 9, "Sensor Boost", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let instruction_set: Vec<i64> =
             input[0].split(',').filter_map(|p| p.parse().ok()).collect();
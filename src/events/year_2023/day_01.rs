@@ -3,7 +3,7 @@ use fancy_regex::Regex;
 
 use crate::utils::AoCResult;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {
     regex: Regex,
@@ -34,7 +34,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2023, 1, "Trebuchet?!", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let sum: i64 = input
             .iter()
@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use crate::utils::AoCResult;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {
     red_max: usize,
@@ -51,7 +51,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2023, 2, "Cube Conundrum", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let mut valid_games = vec![];
 
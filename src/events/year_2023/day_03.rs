@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -12,7 +12,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2023, 3, "Gear Ratios", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let mut valid_parts: Vec<usize> = vec![];
 
@@ -3,7 +3,7 @@ use anyhow::Result;
 
 use self::slice_utils::GrpBy;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 enum Card {
@@ -136,7 +136,11 @@ impl Day {
 }
 // A, K, Q, J, T
 // 32T3K 765
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2023, 7, "Camel Cards", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let mut plays: Vec<_> = input
             .iter()
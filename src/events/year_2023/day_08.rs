@@ -4,7 +4,7 @@ use anyhow::Result;
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -21,7 +21,11 @@ struct Node {
     pub right: String,
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2023, 8, "Haunted Wasteland", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let (pattern, graph) = parse(input);
 
@@ -5,7 +5,7 @@ use tracing::debug;
 
 use crate::utils::{grid::Grid2D, *};
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Pipe {
@@ -110,7 +110,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2023, 10, "Pipe Maze", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let grid = Grid2D::parse(input, |s| s.chars().map(Pipe::parse).collect());
         let start = grid.find(|x| matches!(x, &Pipe::Start)).unwrap();
@@ -132,24 +136,17 @@ impl AocDay for Day {
 fn get_pipe_path(start: &Point, grid: &Grid2D<Pipe>) -> Vec<Point> {
     let start = *start;
 
-    // lets get all neighbours that are not ground
-    let connecting_neighbours =
-        get_neighbours_grid_cmp(start, grid, |p| !matches!(p, &Pipe::Ground));
-    let neighbouring_pipes: Vec<_> = connecting_neighbours
-        .iter()
-        .map(|&p| grid.get(p).unwrap())
-        .collect();
-    let connectors: Vec<_> = connecting_neighbours
-        .iter()
-        .zip(neighbouring_pipes)
-        .filter(|(point, pipe)| pipe.can_connect(point, &start))
+    // lets get all neighbouring pipes that connect to the start
+    let connectors: Vec<_> = grid
+        .neighbours4(start)
+        .filter(|(point, pipe)| !matches!(pipe, Pipe::Ground) && pipe.can_connect(point, &start))
         .map(|(point, _)| point)
         .collect();
 
     debug!("{:?}", connectors);
 
     let mut prev = start;
-    let mut current = **connectors.first().unwrap();
+    let mut current = *connectors.first().unwrap();
     let mut visited = Vec::new();
 
     while current != start {
@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use crate::utils::{grid::Grid2D, *};
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -12,7 +12,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2023, 11, "Cosmic Expansion", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let galaxy = Grid2D::parse(input, |str| str.chars().map(|c| c == '#').collect());
 
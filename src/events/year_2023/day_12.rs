@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -50,7 +50,11 @@ impl ConditionReport {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2023, 12, "Hot Springs", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let _reports: Vec<_> = input.iter().map(ConditionReport::parse).collect();
 
@@ -5,7 +5,7 @@ use tracing::debug;
 
 use crate::utils::{grid::Grid2D, *};
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 enum RowCol {
@@ -45,7 +45,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2023, 13, "Point of Incidence", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let parts = slice_utils::split_chunk_empty(input);
         let grids: Vec<_> = parts
@@ -93,7 +97,7 @@ impl AocDay for Day {
 }
 
 fn find_reflection<M: Fn(&[usize], &[usize]) -> bool>(grid: &Grid2D<Env>, matches: M) -> RowCol {
-    let col_values = get_col_values(grid);
+    let col_values = get_row_values(&grid.transpose());
     let row_values = get_row_values(grid);
 
     for i in 1..col_values.len() {
@@ -165,27 +169,12 @@ fn is_power_of_10_diff(diff: usize) -> bool {
     diff == 1
 }
 
-fn get_col_values(grid: &Grid2D<Env>) -> Vec<usize> {
-    let mut values = vec![];
-
-    for i in 0..grid.width() {
-        let mut value = 0;
-        for (index, &env_value) in grid.get_col(i).into_iter().enumerate() {
-            if env_value == Env::Rock {
-                value += 10_usize.pow(index as u32);
-            }
-        }
-        values.push(value);
-    }
-    values
-}
-
 fn get_row_values(grid: &Grid2D<Env>) -> Vec<usize> {
     let mut values = vec![];
 
     for i in 0..grid.height() {
         let mut value = 0;
-        for (index, &env_value) in grid.get_row(i).into_iter().enumerate() {
+        for (index, &env_value) in grid.row_slice(i).iter().enumerate() {
             if env_value == Env::Rock {
                 value += 10_usize.pow(index as u32);
             }
@@ -1,12 +1,11 @@
-use core::panic;
-use std::{collections::HashMap, fmt::Display};
+use std::fmt::Display;
 
 use anyhow::Result;
 use tracing::debug;
 
-use crate::utils::{grid::Grid2D, *};
+use crate::utils::{cycle, grid::Grid2D, *};
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
 enum Rock {
@@ -43,132 +42,61 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2023, 14, "Parabolic Reflector Dish", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let mut grid = Grid2D::parse(input, |line| line.chars().map(Rock::parse).collect());
 
         tilt_north(&mut grid);
 
         debug!("{}", grid);
-        let mut total_value = 0;
-
-        for row in 0..grid.height() {
-            let value = grid.height() - row;
-
-            let round_count = grid
-                .get_row(row)
-                .into_iter()
-                .filter(|item| **item == Rock::Round)
-                .count();
-
-            let row_value = round_count * value;
-            total_value += row_value;
-            debug!(
-                "Row {} has {} round rocks with {} value each = {}",
-                row, round_count, value, row_value
-            );
-        }
-
-        Ok(total_value.into())
+        Ok(north_load(&grid).into())
     }
 
     fn run_part2(&mut self, input: &[String]) -> Result<AoCResult> {
         const ITERATIONS: usize = 1_000_000_000;
 
-        let mut grid = Grid2D::parse(input, |line| line.chars().map(Rock::parse).collect());
-        let mut iteration = 0;
-        let mut seen = HashMap::new();
-
-        let mut expected_repeat = None;
-        let mut repeat_times = 0;
-
-        // Basically we're expecting the sequence to repeat at one point.
-        // Once we find the repeating cycle, we can just jump forward to the last couple of iterations, which we have to do.
-        // We know it repeats, so we can find where to iterate through the last by
-        // ITERATIONS - current_iteration gives us our missing iteration count.
-        // then we can reduce the missing iteration count by taking missing_iterations mod cycle_time - 1 ("-1" since we're currently already at a repeating iteration)
-
-        // this means that we at max only have to iterate till we find a repeating pattern, and then one cycle_time more.
+        let grid = Grid2D::parse(input, |line| line.chars().map(Rock::parse).collect());
 
-        while iteration < ITERATIONS {
+        // The spin cycles start repeating quickly, so most of the iterations can be skipped
+        let spin = |grid: &Grid2D<Rock>| {
+            let mut grid = grid.clone();
             tilt_north(&mut grid);
             tilt_west(&mut grid);
             tilt_south(&mut grid);
             tilt_east(&mut grid);
+            grid
+        };
+        let grid = cycle::nth_by_key(grid, spin, Grid2D::hash_state, ITERATIONS);
 
-            let hash = grid.hash_state();
-
-            if let Some((expected_next, last, diff)) = expected_repeat
-                && iteration == expected_next
-            {
-                let hash_last = seen.entry(hash).or_default();
-
-                if last != *hash_last {
-                    println!("last: {} -- hash_last {}", last, hash_last);
-                    panic!("assertion failed");
-                }
-
-                repeat_times += 1;
-                if repeat_times > 5 {
-                    // just checking that it actually repeats. Is not really needed
-
-                    let remainder = ITERATIONS - iteration;
-                    let post = remainder % diff - 1;
-
-                    for _i in 0..post {
-                        tilt_north(&mut grid);
-                        tilt_west(&mut grid);
-                        tilt_south(&mut grid);
-                        tilt_east(&mut grid);
-                    }
-
-                    let mut total_value = 0;
-
-                    for row in 0..grid.height() {
-                        let value = grid.height() - row;
-
-                        let round_count = grid
-                            .get_row(row)
-                            .into_iter()
-                            .filter(|item| **item == Rock::Round)
-                            .count();
-
-                        let row_value = round_count * value;
-                        total_value += row_value;
-                        debug!(
-                            "Row {} has {} round rocks with {} value each = {}",
-                            row, round_count, value, row_value
-                        );
-                    }
+        Ok(north_load(&grid).into())
+    }
+}
 
-                    return Ok(total_value.into());
-                }
+fn north_load(grid: &Grid2D<Rock>) -> usize {
+    let mut total_value = 0;
 
-                expected_repeat = Some((iteration + diff, last, diff));
-            }
+    for row in 0..grid.height() {
+        let value = grid.height() - row;
 
-            let entry = seen.entry(hash);
-
-            match entry {
-                // if we found a repeat, lets try to identify when it repeats next
-                std::collections::hash_map::Entry::Occupied(last_seen) => {
-                    if expected_repeat.is_none() {
-                        let last = *last_seen.get();
-                        let diff = iteration - last;
-                        let next = iteration + diff;
-                        expected_repeat = Some((next, last, diff));
-                    }
-                }
-                std::collections::hash_map::Entry::Vacant(vacant) => {
-                    vacant.insert(iteration);
-                }
-            };
-
-            iteration += 1;
-        }
+        let round_count = grid
+            .get_row(row)
+            .into_iter()
+            .filter(|item| **item == Rock::Round)
+            .count();
 
-        unreachable!()
+        let row_value = round_count * value;
+        total_value += row_value;
+        debug!(
+            "Row {} has {} round rocks with {} value each = {}",
+            row, round_count, value, row_value
+        );
     }
+
+    total_value
 }
 
 fn tilt_north(grid: &mut Grid2D<Rock>) {
@@ -200,91 +128,23 @@ fn tilt_north(grid: &mut Grid2D<Rock>) {
     }
 }
 
+// The other directions rotate the grid so they become north
 fn tilt_south(grid: &mut Grid2D<Rock>) {
-    fn find_destination(grid: &&mut Grid2D<Rock>, current_row: usize, col_index: usize) -> usize {
-        for row in current_row + 1..grid.height() {
-            if *grid.get((col_index, row)).unwrap() != Rock::Empty {
-                return row - 1;
-            }
-        }
-        grid.height() - 1
-    }
-
-    let rows = grid.height();
-    for row_index in (0..rows - 1).rev() {
-        let row: Vec<_> = grid.get_row(row_index).into_iter().copied().collect();
-        for (col_index, _) in row
-            .iter()
-            .enumerate()
-            .filter(|(_, rock)| **rock == Rock::Round)
-        {
-            let destination_row = find_destination(&grid, row_index, col_index);
-
-            let rock_origin = grid.get_mut((col_index, row_index)).unwrap();
-            *rock_origin = Rock::Empty;
-
-            let rock_destination = grid.get_mut((col_index, destination_row)).unwrap();
-            *rock_destination = Rock::Round;
-        }
-    }
+    *grid = grid.flip_vertical();
+    tilt_north(grid);
+    *grid = grid.flip_vertical();
 }
 
 fn tilt_west(grid: &mut Grid2D<Rock>) {
-    fn find_destination(grid: &&mut Grid2D<Rock>, current_col: usize, row_index: usize) -> usize {
-        for col in (0..current_col).rev() {
-            if *grid.get((col, row_index)).unwrap() != Rock::Empty {
-                return col + 1;
-            }
-        }
-        0
-    }
-
-    let cols = grid.width();
-    for col_index in 1..cols {
-        let col: Vec<_> = grid.get_col(col_index).into_iter().copied().collect();
-        for (row_index, _) in col
-            .iter()
-            .enumerate()
-            .filter(|(_, rock)| **rock == Rock::Round)
-        {
-            let destination_col = find_destination(&grid, col_index, row_index);
-
-            let rock_origin = grid.get_mut((col_index, row_index)).unwrap();
-            *rock_origin = Rock::Empty;
-
-            let rock_destination = grid.get_mut((destination_col, row_index)).unwrap();
-            *rock_destination = Rock::Round;
-        }
-    }
+    *grid = grid.rotate_cw();
+    tilt_north(grid);
+    *grid = grid.rotate_ccw();
 }
 
 fn tilt_east(grid: &mut Grid2D<Rock>) {
-    fn find_destination(grid: &&mut Grid2D<Rock>, current_col: usize, row_index: usize) -> usize {
-        for col in current_col + 1..grid.width() {
-            if *grid.get((col, row_index)).unwrap() != Rock::Empty {
-                return col - 1;
-            }
-        }
-        grid.width() - 1
-    }
-
-    let cols = grid.width();
-    for col_index in (0..cols - 1).rev() {
-        let col: Vec<_> = grid.get_col(col_index).into_iter().copied().collect();
-        for (row_index, _) in col
-            .iter()
-            .enumerate()
-            .filter(|(_, rock)| **rock == Rock::Round)
-        {
-            let destination_col = find_destination(&grid, col_index, row_index);
-
-            let rock_origin = grid.get_mut((col_index, row_index)).unwrap();
-            *rock_origin = Rock::Empty;
-
-            let rock_destination = grid.get_mut((destination_col, row_index)).unwrap();
-            *rock_destination = Rock::Round;
-        }
-    }
+    *grid = grid.rotate_ccw();
+    tilt_north(grid);
+    *grid = grid.rotate_cw();
 }
 
 #[cfg(test)]
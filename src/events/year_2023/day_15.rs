@@ -5,7 +5,7 @@ use tracing::debug;
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 #[derive(Debug, Clone)]
 struct Lens(String, char, usize);
@@ -87,7 +87,13 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2023, 15, "Lens Library", || {
+        std::boxed::Box::new(Day::new())
+    })
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let inputs = input[0].split(',');
 
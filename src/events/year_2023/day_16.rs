@@ -7,7 +7,7 @@ use anyhow::Result;
 
 use crate::utils::{grid::Grid2D, *};
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 enum Mirror {
@@ -103,7 +103,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2023, 16, "The Floor Will Be Lava", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let mut grid = Grid2D::parse(input, |l| l.chars().map(Mirror::parse).collect());
 
@@ -1,10 +1,8 @@
-use std::collections::{BinaryHeap, HashMap};
-
 use anyhow::Result;
 
-use crate::utils::{grid::Grid2D, vec2d::Vec2D, *};
+use crate::utils::{grid::Grid2D, search::dijkstra, vec2d::Vec2D, *};
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -60,30 +58,11 @@ impl Node {
     }
 }
 
-// The state we'll track in our priority queue. We need to track the
-// node above and the cost to get there.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct State {
-    cost: usize,
-    node: Node,
-}
-
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        // We are using a min heap, so we are doing this backwards.
-        other
-            .cost
-            .cmp(&self.cost)
-            .then_with(|| self.node.cmp(&other.node))
-    }
+inventory::submit! {
+    Solution::new(2023, 17, "Clumsy Crucible", || Box::new(Day::new()))
 }
 
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-impl AocDay for Day {
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let grid = Grid2D::parse_char(input, |c| c.to_digit(10).unwrap());
 
@@ -91,7 +70,7 @@ impl AocDay for Day {
         let end = Point::new(grid.width() - 1, grid.height() - 1);
         let end_condition = |node: &Node| node.position == end;
 
-        let path_cost = dijkstra::<1, 3, _>(&grid, &start, end_condition).unwrap_or_default();
+        let path_cost = least_heat_loss::<1, 3>(&grid, start, end_condition);
 
         Ok(path_cost.into())
     }
@@ -103,7 +82,7 @@ impl AocDay for Day {
         let end = Point::new(grid.width() - 1, grid.height() - 1);
         let end_condition = |node: &Node| node.position == end && node.direction_count >= 4;
 
-        let path_cost = dijkstra::<4, 10, _>(&grid, &start, end_condition).unwrap_or_default();
+        let path_cost = least_heat_loss::<4, 10>(&grid, start, end_condition);
 
         Ok(path_cost.into())
     }
@@ -119,7 +98,9 @@ fn neighbors<const MIN: usize, const MAX: usize>(node: &Node, grid: &Grid2D<u32>
         // Cant go straight back
         if direction == node.direction.inverse() {
             continue;
-        } else if direction != node.direction && node.direction_count >= MIN {
+        } else if direction != node.direction
+            && (node.direction_count >= MIN || node.direction_count == 0)
+        {
             // New direction, reset counter. We can only change direction if we have already headed
             // in the same direction a minimum of MIN times (1 in part1 and 4 in part2)
             neighbors.push(Node::new(point, direction, 1));
@@ -132,62 +113,23 @@ fn neighbors<const MIN: usize, const MAX: usize>(node: &Node, grid: &Grid2D<u32>
     neighbors
 }
 
-fn dijkstra<const MIN: usize, const MAX: usize, G>(
+fn least_heat_loss<const MIN: usize, const MAX: usize>(
     grid: &Grid2D<u32>,
-    start: &Point,
-    goal_fn: G,
-) -> Option<usize>
-where
-    G: Fn(&Node) -> bool,
-{
-    // Track our min distances at each Node. In our specific case, we
-    // have multiple because we could be coming from Up or Left
-    // at the start.
-    let mut distances = HashMap::new();
-    distances.insert(Node::new(start.clone(), Direction::Down, 0), 0);
-    distances.insert(Node::new(start.clone(), Direction::Right, 0), 0);
-
-    // Track paths we want to visit. Again, we are adding two because
-    // we could be coming from either.
-    let mut frontier = BinaryHeap::new();
-    frontier.push(State {
-        cost: 0,
-        node: Node::new(start.clone(), Direction::Down, 0),
-    });
-    frontier.push(State {
-        cost: 0,
-        node: Node::new(start.clone(), Direction::Right, 0),
-    });
-
-    // Grab the next node from the frontier.
-    while let Some(State { cost, node }) = frontier.pop() {
-        // If we are at the goal, we are done.
-        if goal_fn(&node) {
-            return Some(cost);
-        }
-
-        // Otherwise, check our neighbors.
-        for neighbor in neighbors::<MIN, MAX>(&node, grid) {
-            // If we've already visited this node and it was cheaper,
-            // we don't need to keep checking this way.
-            let new_cost = cost + *grid.get(neighbor.position).unwrap() as usize;
-
-            if let Some(&best) = distances.get(&neighbor) {
-                if new_cost >= best {
-                    continue;
-                }
-            }
-
-            // Otherwise, add it to our distances and frontier.
-            distances.insert(neighbor.clone(), new_cost);
-            frontier.push(State {
-                cost: new_cost,
-                node: neighbor,
-            });
-        }
-    }
-
-    // If we get here, we didn't find a path. Not possible in our
-    // case, but is in the general case.
-    None
+    start: Point,
+    goal_fn: impl Fn(&Node) -> bool,
+) -> usize {
+    // Nothing has been counted at the start yet, so it can head either way
+    let start = Node::new(start, Direction::Right, 0);
+
+    let successors = |node: &Node| {
+        neighbors::<MIN, MAX>(node, grid)
+            .into_iter()
+            .map(|neighbor| {
+                let cost = *grid.get(neighbor.position).unwrap() as usize;
+                (neighbor, cost)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    dijkstra(start, successors, goal_fn).map_or(0, |found| found.cost)
 }
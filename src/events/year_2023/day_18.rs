@@ -2,9 +2,9 @@ use std::collections::{HashSet, VecDeque};
 
 use anyhow::Result;
 
-use crate::utils::{grid::Grid2D, point::IPoint, *};
+use crate::utils::{point::IPoint, sparse_grid::SparseGrid, *};
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum Direction {
@@ -70,7 +70,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2023, 18, "Lavaduct Lagoon", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let plans: Vec<_> = input.iter().map(|line| DigPlan::parse(line)).collect();
 
@@ -132,25 +136,7 @@ fn picks_theorem_and_shoelace(points: &[IPoint]) -> i64 {
 }
 
 fn fill(points: &[IPoint]) -> usize {
-    let mut max_x = 0;
-    let mut max_y = 0;
-    let mut x_offset = 0;
-    let mut y_offset = 0;
-    for p in points.iter() {
-        max_x = max_x.max(p.x());
-        max_y = max_y.max(p.y());
-        x_offset = x_offset.min(p.x());
-        y_offset = y_offset.min(p.y());
-    }
-
-    x_offset = x_offset.abs();
-    y_offset = y_offset.abs();
-
-    let mut grid = Grid2D::new(
-        (x_offset + max_x + 1) as usize,
-        (y_offset + max_y + 1) as usize,
-        '.',
-    );
+    let mut trench = SparseGrid::new();
 
     for i in 0..points.len() - 1 {
         let p1 = points[i];
@@ -161,22 +147,18 @@ fn fill(points: &[IPoint]) -> usize {
                 true => p1.y()..=p2.y(),
                 false => p2.y()..=p1.y(),
             };
-            for y in range {
-                let p = Point::new((p1.x() + x_offset) as usize, (y + y_offset) as usize);
-                *grid.get_mut(p).unwrap() = '#';
-            }
+            trench.extend(range.map(|y| (IPoint::new(p1.x(), y), '#')));
         } else {
             let range = match p1.x() < p2.x() {
                 true => p1.x()..=p2.x(),
                 false => p2.x()..=p1.x(),
             };
-            for x in range {
-                let p = Point::new((x + x_offset) as usize, (p1.y() + y_offset) as usize);
-                *grid.get_mut(p).unwrap() = '#';
-            }
+            trench.extend(range.map(|x| (IPoint::new(x, p1.y()), '#')));
         }
     }
 
+    let mut grid = trench.to_grid('.').unwrap();
+
     let start_flood_fill = Point::new(grid.width() / 2, grid.height() / 2);
 
     let mut visited = HashSet::new();
@@ -7,7 +7,7 @@ use anyhow::Result;
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{AocDay, RunContext, Solution};
 
 pub struct Day {}
 
@@ -17,8 +17,19 @@ impl Day {
     }
 }
 
+inventory::submit! {
+    Solution::new(2023, 19, "Aplenty", || Box::new(Day::new()))
+}
+
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    items: Vec<[usize; 4]>,
+}
+
 impl AocDay for Day {
-    fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
+    type Input = System;
+
+    fn parse(&mut self, input: &[String], _: &RunContext) -> Result<Self::Input> {
         let input = slice_utils::split_chunk_empty(input);
 
         let workflows: HashMap<_, _> = input[0]
@@ -32,23 +43,25 @@ impl AocDay for Day {
             .map(|item| parse_item(item.as_str()))
             .collect();
 
-        let total: usize = items.iter().map(|item| get_value(&workflows, item)).sum();
+        Ok(System { workflows, items })
+    }
+
+    fn run_part1(&mut self, system: &Self::Input, _: &RunContext) -> Result<AoCResult> {
+        let total: usize = system
+            .items
+            .iter()
+            .map(|item| get_value(&system.workflows, item))
+            .sum();
 
         Ok(total.into())
     }
 
-    fn run_part2(&mut self, input: &[String]) -> Result<AoCResult> {
-        let input = slice_utils::split_chunk_empty(input);
-
-        let workflows: HashMap<_, _> = input[0]
-            .iter()
-            .map(|line| Workflow::parse(line.as_str()))
-            .map(|wf| (wf.name.clone(), wf))
-            .collect();
+    fn run_part2(&mut self, system: &Self::Input, _: &RunContext) -> Result<AoCResult> {
+        let workflows = &system.workflows;
 
         let mut accepted_paths_counts = 0;
 
-        for path in find_paths(&workflows) {
+        for path in find_paths(workflows) {
             let mut min_max: Vec<Vec<_>> = (0..4).map(|_| vec![1, 4000]).collect();
 
             for cond in path {
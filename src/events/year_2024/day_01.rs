@@ -5,7 +5,7 @@ use slice_utils::GrpBy;
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -15,7 +15,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2024, 1, "Historian Hysteria", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let (mut l1, mut l2): (Vec<i64>, Vec<i64>) = input
             .into_iter()
@@ -4,7 +4,7 @@ use anyhow::Result;
 
 use crate::utils::{self, *};
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -14,7 +14,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2024, 5, "Print Queue", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let mut res = crate::utils::slice_utils::split_chunk_empty(input);
 
@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 const P1_OPERATORS: [Operator; 2] = [Operator::Add, Operator::Mul];
 const P2_OPERATORS: [Operator; 3] = [Operator::Add, Operator::Mul, Operator::Concat];
@@ -22,7 +22,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2024, 7, "Bridge Repair", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let parts: Vec<(usize, Vec<usize>)> = input
             .into_iter()
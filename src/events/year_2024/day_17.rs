@@ -1,13 +1,10 @@
 use std::ops::BitXor;
 
 use anyhow::Result;
-use variable::{variable, Variable};
 
 use crate::utils::*;
 
-use super::super::AocDay;
-
-const TEST: Variable<bool> = variable(true, false);
+use super::super::{AocDay, RunContext, Solution};
 
 pub struct Day {}
 
@@ -17,9 +14,19 @@ impl Day {
     }
 }
 
+inventory::submit! {
+    Solution::new(2024, 17, "Chronospatial Computer", || Box::new(Day::new()))
+}
+
 impl AocDay for Day {
-    fn run_part1(&mut self, _: &[String]) -> Result<AoCResult> {
-        let mut computer = get_computer();
+    type Input = Vec<String>;
+
+    fn parse(&mut self, input: &[String], _: &RunContext) -> Result<Self::Input> {
+        Ok(input.to_vec())
+    }
+
+    fn run_part1(&mut self, _: &Self::Input, ctx: &RunContext) -> Result<AoCResult> {
+        let mut computer = get_computer(ctx.test);
         computer.run();
 
         Ok(computer
@@ -31,16 +38,16 @@ impl AocDay for Day {
             .into())
     }
 
-    fn run_part2(&mut self, _: &[String]) -> Result<AoCResult> {
-        let instructions = get_computer().instruction_cache;
+    fn run_part2(&mut self, _: &Self::Input, ctx: &RunContext) -> Result<AoCResult> {
+        let instructions = get_computer(ctx.test).instruction_cache;
 
-        let reg_a = solve(&instructions, instructions.len(), 0).unwrap();
+        let reg_a = solve(&instructions, instructions.len(), 0, ctx.test).unwrap();
 
         Ok(reg_a.into())
     }
 }
 
-fn solve(instructions: &[u8], idx: usize, reg_a: i64) -> Option<i64> {
+fn solve(instructions: &[u8], idx: usize, reg_a: i64, test: bool) -> Option<i64> {
     // if we reach the end of the line, just return here. We've outputted all the numbers
     let Some(idx) = idx.checked_sub(1) else {
         return Some(reg_a);
@@ -50,12 +57,12 @@ fn solve(instructions: &[u8], idx: usize, reg_a: i64) -> Option<i64> {
 
     for i in 0..8 {
         let reg_a = reg_a << 3 | i;
-        let mut computer = get_computer2(reg_a);
+        let mut computer = get_computer2(reg_a, test);
         while computer.forward().is_some() {}
 
         // at current iteration, we only need it to output the next value.
         if *computer.output.first().unwrap() == expected_output {
-            if let Some(result) = solve(instructions, idx, reg_a) {
+            if let Some(result) = solve(instructions, idx, reg_a, test) {
                 return Some(result);
             }
         }
@@ -63,8 +70,8 @@ fn solve(instructions: &[u8], idx: usize, reg_a: i64) -> Option<i64> {
     None
 }
 
-fn get_computer() -> Computer {
-    if *TEST {
+fn get_computer(test: bool) -> Computer {
+    if test {
         Computer {
             instruction_cache: vec![0, 1, 5, 4, 3, 0],
             instruction_ptr: 0,
@@ -85,8 +92,8 @@ fn get_computer() -> Computer {
     }
 }
 
-fn get_computer2(reg: i64) -> Computer {
-    if *TEST {
+fn get_computer2(reg: i64, test: bool) -> Computer {
+    if test {
         Computer {
             instruction_cache: vec![0, 3, 5, 4, 3, 0],
             instruction_ptr: 0,
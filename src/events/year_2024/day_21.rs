@@ -3,7 +3,7 @@ use itertools::Itertools;
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 const NUM_CHARS: usize = 11;
 
@@ -22,7 +22,11 @@ impl Day {
 //  the final count is just a dot with the vector of all 1s => precompute dot with matrix
 //  => each part reduces to a single dot product
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2024, 21, "Keypad Conundrum", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let mut counts = vec![0; NUM_CHARS * NUM_CHARS];
 
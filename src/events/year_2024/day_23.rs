@@ -7,7 +7,7 @@ use anyhow::Result;
 
 use crate::utils::*;
 
-use super::super::AocDay;
+use super::super::{AocDay, RunContext, Solution};
 
 pub struct Day {}
 
@@ -17,12 +17,20 @@ impl Day {
     }
 }
 
+inventory::submit! {
+    Solution::new(2024, 23, "LAN Party", || Box::new(Day::new()))
+}
+
 impl AocDay for Day {
-    fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
-        let map = parse(input);
+    type Input = HashMap<u16, Vec<u16>>;
 
+    fn parse(&mut self, input: &[String], _: &RunContext) -> Result<Self::Input> {
+        Ok(parse(input))
+    }
+
+    fn run_part1(&mut self, map: &Self::Input, _: &RunContext) -> Result<AoCResult> {
         let connections: HashSet<Connection> =
-            map.keys().flat_map(|&key| union(key, &map)).collect();
+            map.keys().flat_map(|&key| union(key, map)).collect();
 
         Ok(connections
             .into_iter()
@@ -31,9 +39,7 @@ impl AocDay for Day {
             .into())
     }
 
-    fn run_part2(&mut self, input: &[String]) -> Result<AoCResult> {
-        let map = parse(input);
-
+    fn run_part2(&mut self, map: &Self::Input, _: &RunContext) -> Result<AoCResult> {
         Ok(biggest_union(map).into())
     }
 }
@@ -72,7 +78,7 @@ fn union(key: u16, map: &HashMap<u16, Vec<u16>>) -> Vec<Connection> {
     result
 }
 
-fn biggest_union(map: HashMap<u16, Vec<u16>>) -> String {
+fn biggest_union(map: &HashMap<u16, Vec<u16>>) -> String {
     let mut biggest_clique = vec![];
     let mut has_seen = HashSet::new();
 
@@ -92,7 +98,7 @@ fn biggest_union(map: HashMap<u16, Vec<u16>>) -> String {
 
         for &conn in conns {
             // If can connect to all edges
-            if current_clique.iter().all(|&c| can_connect(conn, c, &map)) {
+            if current_clique.iter().all(|&c| can_connect(conn, c, map)) {
                 has_seen.insert(conn); // We have evaluated this connection
                 current_clique.push(conn);
             }
@@ -171,7 +177,7 @@ mod tests {
         let input: Vec<String> = vec!["ta-ch".into(), "ch-xx".into(), "xx-ta".into()];
         let map = parse(&input);
 
-        let un = biggest_union(map);
+        let un = biggest_union(&map);
 
         assert_eq!(un, "ch,ta,xx")
     }
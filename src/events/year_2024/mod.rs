@@ -10,6 +10,6 @@ pub mod day_13;
 pub mod day_15;
 pub mod day_17;
 pub mod day_19;
+pub mod day_21;
 pub mod day_23;
 pub mod day_25;
-pub mod day_21;
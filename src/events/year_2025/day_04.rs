@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use crate::utils::{grid::Grid2D, *};
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -12,7 +12,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2025, 4, "Printing Department", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let mut total = 0;
         let grid = Grid2D::parse_char(input, |x| x == '@');
@@ -22,7 +26,7 @@ impl AocDay for Day {
                 continue;
             }
 
-            if get_neighbours_grid_cmp(point, &grid, |&x| x).len() < 4 {
+            if grid.neighbours8(point).filter(|(_, x)| **x).count() < 4 {
                 total += 1;
             }
         }
@@ -41,7 +45,7 @@ impl AocDay for Day {
                     continue;
                 }
 
-                if get_neighbours_grid_cmp(point, &grid, |&x| x).len() < 4 {
+                if grid.neighbours8(point).filter(|(_, x)| **x).count() < 4 {
                     current_round_removal += 1;
                     grid.set(point, false);
                 }
@@ -1,10 +1,10 @@
-use std::{collections::VecDeque, fmt::Display, ops::AddAssign};
+use std::{collections::VecDeque, fmt::Display};
 
 use anyhow::Result;
 
-use crate::utils::{grid::Grid2D, vec2d::Vec2D, *};
+use crate::utils::{grid::Grid2D, search::count_paths, vec2d::Vec2D, *};
 
-use super::super::AocDay;
+use super::super::{Solution, UntypedAocDay};
 
 pub struct Day {}
 
@@ -14,7 +14,11 @@ impl Day {
     }
 }
 
-impl AocDay for Day {
+inventory::submit! {
+    Solution::new(2025, 7, "Laboratories", || Box::new(Day::new()))
+}
+
+impl UntypedAocDay for Day {
     fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
         let mut grid = Grid2D::parse_char(&input[1..], |c| {
             if c == '^' {
@@ -61,7 +65,7 @@ impl AocDay for Day {
     }
 
     fn run_part2(&mut self, input: &[String]) -> Result<AoCResult> {
-        let mut grid = Grid2D::parse_char(&input[1..], |c| {
+        let grid = Grid2D::parse_char(&input[1..], |c| {
             if c == '^' {
                 Tachyon::Splitter
             } else {
@@ -69,57 +73,23 @@ impl AocDay for Day {
             }
         });
 
-        // Set start beam
+        // Every path down to the last row is a timeline
         let start_idx = input[0].find('S').unwrap();
-        grid.set(Point::new(start_idx, 0), Tachyon::Beam(1));
-
-        let mut beam_queue = VecDeque::new();
-        beam_queue.push_back(Point::new(start_idx, 0));
-
-        while let Some(mut beam_position) = beam_queue.pop_front() {
-            let current = *grid.get(beam_position).unwrap();
-            beam_position += Vec2D::DOWN;
-
-            // check if split
-            if grid.get(beam_position) == Some(&Tachyon::Splitter) {
-                let left = beam_position.sub_x(1);
-                let right = beam_position.add_x(1);
-
-                let points_to_add: Vec<_> = [left, right]
-                    .into_iter()
-                    .filter_map(|x| x)
-                    .filter(|&p| grid.is_within_bounds(p))
-                    .collect();
-
-                for p in points_to_add {
-                    let entry = grid.get_mut(p).unwrap();
-                    match entry {
-                        Tachyon::Empty => {
-                            *entry = current;
-                            beam_queue.push_back(p);
-                        }
-                        Tachyon::Beam(count) => count.add_assign(current.count()),
-                        Tachyon::Splitter => todo!(),
-                    }
-                }
-            } else if grid.is_within_bounds(beam_position) {
-                let entry = grid.get_mut(beam_position).unwrap();
-                match entry {
-                    Tachyon::Empty => {
-                        *entry = current;
-                        beam_queue.push_back(beam_position);
-                    }
-                    Tachyon::Beam(count) => count.add_assign(current.count()),
-                    Tachyon::Splitter => todo!(),
-                };
+        let last_row = grid.height() - 1;
+
+        let successors = |&beam_position: &Point| {
+            let below = beam_position + Vec2D::DOWN;
+            match grid.get(below) {
+                Some(Tachyon::Splitter) => vec![below.sub_x(1), below.add_x(1)],
+                Some(_) => vec![Some(below)],
+                None => vec![],
             }
-        }
-
-        let count: usize = grid
-            .get_row(grid.height() - 1)
             .into_iter()
-            .map(|x| x.count())
-            .sum();
+            .flatten()
+            .filter(|&p| grid.is_within_bounds(p))
+        };
+
+        let count = count_paths(Point::new(start_idx, 0), successors, |p| p.y() == last_row);
 
         Ok(count.into())
     }
@@ -5,9 +5,9 @@ use std::{
 
 use anyhow::Result;
 
-use crate::utils::{variable::variable, *};
+use crate::utils::{variable::param, *};
 
-use super::super::AocDay;
+use super::super::{AocDay, RunContext, Solution};
 
 pub struct Day {}
 
@@ -17,14 +17,24 @@ impl Day {
     }
 }
 
+inventory::submit! {
+    Solution::new(2025, 8, "Playground", || Box::new(Day::new()))
+}
+
 impl AocDay for Day {
-    fn run_part1(&mut self, input: &[String]) -> Result<AoCResult> {
-        let junctions_to_connect = variable(10usize, 1000);
+    type Input = Vec<String>;
+
+    fn parse(&mut self, input: &[String], _: &RunContext) -> Result<Self::Input> {
+        Ok(input.to_vec())
+    }
+
+    fn run_part1(&mut self, input: &Self::Input, ctx: &RunContext) -> Result<AoCResult> {
+        let junctions_to_connect = param("connections", 10usize, 1000);
 
         let (mut junctions, mut circuits) = parse(input);
 
         // Find max based on size.
-        let connections = find(&junctions, *junctions_to_connect);
+        let connections = find(&junctions, junctions_to_connect.get(ctx)?);
         let connections = connections.into_sorted_vec();
         // reverse them into sorted
 
@@ -62,7 +72,7 @@ impl AocDay for Day {
             .into())
     }
 
-    fn run_part2(&mut self, input: &[String]) -> Result<AoCResult> {
+    fn run_part2(&mut self, input: &Self::Input, _: &RunContext) -> Result<AoCResult> {
         let junction_count = input.len();
 
         let (mut junctions, mut circuits) = parse(input);
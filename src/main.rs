@@ -3,21 +3,32 @@
 #![allow(dead_code)]
 
 mod commands;
+mod config;
 mod day_generator;
 pub mod events;
+#[cfg(test)]
+mod test_utils;
 pub mod utils;
 pub mod vm;
 
 use anyhow::Result;
 use chrono::Datelike;
 use clap::{Parser, Subcommand, ValueEnum};
-use tracing::{Level, debug};
-use tracing_subscriber::FmtSubscriber;
+use tracing::{Level, debug, warn};
+use tracing_subscriber::{FmtSubscriber, fmt::writer::BoxMakeWriter};
 
-use crate::{commands::InputFetcher, day_generator::DayGenerator};
-
-const DAYS_FOLDER: &str = "./src/events";
-const INPUT_FOLDER: &str = "./inputs";
+use crate::{
+    commands::{
+        InputFetcher,
+        baseline::BenchBaselines,
+        html,
+        ledger::AnswerLedger,
+        output::{Output, OutputFormat},
+    },
+    config::Config,
+    day_generator::DayGenerator,
+    events::RunContext,
+};
 
 #[derive(Parser, Debug)]
 struct AoCOptions {
@@ -32,12 +43,19 @@ struct AoCOptions {
     #[arg(short, long)]
     year: Option<u16>,
 
-    /// Verbose Logging
-    #[arg(short, long)]
-    test: bool,
+    /// Run with an example input, optionally selecting which one like --test=2
+    #[arg(
+        short,
+        long,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "1",
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    test: Option<usize>,
 
-    /// Verbose Logging
-    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    /// Only run this part, 0 runs both even when aoc.toml picks one
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(0..=2))]
     part: Option<u8>,
 
     /// Run with a specific input. Formatted day_<input>.txt
@@ -51,6 +69,58 @@ struct AoCOptions {
     /// Very Verbose Logging
     #[arg(long = "trace")]
     trace: bool,
+
+    /// Compare bench results against the baseline saved under this label
+    #[arg(long)]
+    baseline: Option<String>,
+
+    /// Save bench results as a baseline under this label
+    #[arg(long)]
+    save_baseline: Option<String>,
+
+    /// Stop benchmarking after this many iterations
+    #[arg(long)]
+    bench_iterations: Option<usize>,
+
+    /// Stop benchmarking after this many milliseconds
+    #[arg(long)]
+    bench_time_ms: Option<u64>,
+
+    /// Folder the days are generated into
+    #[arg(long)]
+    days_folder: Option<String>,
+
+    /// Folder the inputs, answers and cookie are kept in
+    #[arg(long)]
+    input_folder: Option<String>,
+
+    /// Folder with the day templates
+    #[arg(long)]
+    templates_folder: Option<String>,
+
+    /// Base address of the Advent of Code server
+    #[arg(long)]
+    base_address: Option<String>,
+
+    /// User-Agent sent with every request to the Advent of Code server, should say how to reach you
+    #[arg(long)]
+    user_agent: Option<String>,
+
+    /// Only use cached inputs, never touching the network
+    #[arg(long)]
+    offline: bool,
+
+    /// Wait for the day to unlock, then fetch its input and example
+    #[arg(long, conflicts_with = "offline")]
+    wait: bool,
+
+    /// Override a parameter of the day, like --param steps=64
+    #[arg(long = "param", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+
+    /// Print results as structured records to stdout
+    #[arg(long, value_enum)]
+    format: Option<OutputFormat>,
 }
 
 #[derive(Subcommand, Debug, Clone, ValueEnum)]
@@ -59,17 +129,42 @@ enum AoCCommands {
     Bench,
     Create,
     BenchAll,
+    Submit,
+    Confirm,
+    VerifyAll,
+    List,
+    Puzzle,
+    Regenerate,
 }
 
-pub static mut TEST: bool = false;
-
 fn main() -> Result<()> {
-    let cli = AoCOptions::parse();
+    let mut cli = AoCOptions::parse();
+    let mut config = Config::load()?;
 
-    if cli.test {
-        unsafe {
-            TEST = true;
-        }
+    // Flags on the command line win over the config file
+    cli.year = cli.year.or(config.year);
+    cli.part = cli.part.or(config.part).filter(|&part| part != 0);
+    cli.format = cli.format.or(config.format);
+    if let Some(iterations) = cli.bench_iterations {
+        config.bench.iterations = iterations;
+    }
+    if let Some(time_ms) = cli.bench_time_ms {
+        config.bench.time_ms = time_ms;
+    }
+    if let Some(days_folder) = cli.days_folder.take() {
+        config.days_folder = days_folder;
+    }
+    if let Some(input_folder) = cli.input_folder.take() {
+        config.input_folder = input_folder;
+    }
+    if let Some(templates_folder) = cli.templates_folder.take() {
+        config.templates_folder = templates_folder;
+    }
+    if let Some(base_address) = cli.base_address.take() {
+        config.base_address = base_address;
+    }
+    if let Some(user_agent) = cli.user_agent.take() {
+        config.user_agent = Some(user_agent);
     }
 
     let subscriber = FmtSubscriber::builder()
@@ -78,10 +173,20 @@ fn main() -> Result<()> {
             (true, false) => Level::DEBUG,
             (false, false) => Level::INFO,
         })
+        // Keep stdout clean for the structured output
+        .with_writer(match cli.format {
+            Some(_) => BoxMakeWriter::new(std::io::stderr),
+            None => BoxMakeWriter::new(std::io::stdout),
+        })
         .finish();
     tracing::subscriber::set_global_default(subscriber)?;
 
-    let day_generator = DayGenerator::new(String::from(DAYS_FOLDER));
+    if let Some(path) = &config.path {
+        debug!("Loaded config from {:?}", path);
+    }
+
+    let day_generator =
+        DayGenerator::new(config.days_folder.clone(), config.templates_folder.clone());
 
     let now = chrono::Utc::now();
 
@@ -100,31 +205,173 @@ fn main() -> Result<()> {
 
     debug!("{:?}", cli);
 
-    match cli.command {
+    let mut output = Output::new(cli.format);
+    let ctx = RunContext {
+        test: cli.test.is_some(),
+        input: cli.input.clone(),
+        verbose: cli.verbose || cli.trace,
+        part: cli.part,
+        params: cli.params.iter().cloned().collect(),
+        ..RunContext::default()
+    };
+
+    if cli.wait {
+        // Create works on the day after the last generated one
+        let day = match (&cli.command, cli.day) {
+            (AoCCommands::Create, None) => day + 1,
+            _ => day,
+        };
+        input_fetcher(&cli, &config).wait_and_fetch(day, year)?;
+    }
+
+    let result = match cli.command {
         AoCCommands::Run => {
-            let mut input_fetcher = InputFetcher::new(INPUT_FOLDER);
-            let input = input_fetcher.fetch(day, year, cli.test, &cli.input);
-            commands::run::run_day(day, year, input, cli.part)
+            let mut input_fetcher = input_fetcher(&cli, &config);
+            let input = input_fetcher.fetch(day, year, cli.test, &cli.input)?;
+            let ledger = answer_ledger(&cli, &config, day, year)?;
+            commands::run::run_day(day, year, input, &ctx, Some(&ledger), &mut output)
         }
         AoCCommands::Bench => {
-            let mut input_fetcher = InputFetcher::new(INPUT_FOLDER);
-            let input = input_fetcher.fetch(day, year, cli.test, &cli.input);
-            commands::bench::bench_day(day, year, input, cli.part)
+            let mut input_fetcher = input_fetcher(&cli, &config);
+            let input = input_fetcher.fetch(day, year, cli.test, &cli.input)?;
+            let mut baselines = BenchBaselines::load(
+                &config.input_folder,
+                cli.baseline,
+                cli.save_baseline,
+                InputFetcher::input_name(cli.test, &cli.input),
+            )?;
+            commands::bench::bench_day(
+                day,
+                year,
+                input,
+                &ctx,
+                &mut baselines,
+                &mut output,
+                config.bench,
+            )
         }
         AoCCommands::BenchAll => {
+            let mut input_fetcher = input_fetcher(&cli, &config);
+            let mut baselines = BenchBaselines::load(
+                &config.input_folder,
+                cli.baseline,
+                cli.save_baseline,
+                InputFetcher::input_name(cli.test, &cli.input),
+            )?;
             for day in (1..=day).filter(|&day| events::has_day(day, year)) {
-                let mut input_fetcher = InputFetcher::new(INPUT_FOLDER);
-                let input = input_fetcher.fetch(day, year, cli.test, &cli.input);
-                commands::bench::bench_day(day, year, input, cli.part)?;
+                let input = input_fetcher.fetch(day, year, cli.test, &cli.input)?;
+                commands::bench::bench_day(
+                    day,
+                    year,
+                    input,
+                    &ctx,
+                    &mut baselines,
+                    &mut output,
+                    config.bench,
+                )?;
+            }
+            Ok(())
+        }
+        AoCCommands::Submit => {
+            // Only answers for the real input can be right, a wrong one earns a timeout
+            if cli.test.is_some() || cli.input.is_some() {
+                anyhow::bail!("Only the real input can be submitted, drop --test and --input");
+            }
+            let mut input_fetcher = input_fetcher(&cli, &config);
+            let input = input_fetcher.fetch(day, year, cli.test, &cli.input)?;
+            let mut ledger = AnswerLedger::load(&config.input_folder)?;
+            commands::submit::submit_day(day, year, input, &ctx, &mut input_fetcher, &mut ledger)
+        }
+        AoCCommands::Confirm => {
+            let mut input_fetcher = input_fetcher(&cli, &config);
+            let input = input_fetcher.fetch(day, year, cli.test, &cli.input)?;
+            let mut ledger = answer_ledger(&cli, &config, day, year)?;
+            commands::run::confirm_day(day, year, input, &ctx, &mut ledger)
+        }
+        AoCCommands::VerifyAll => {
+            let ledger = AnswerLedger::load(&config.input_folder)?;
+            commands::verify::verify_all(&config.input_folder, &ledger)
+        }
+        AoCCommands::List => {
+            for solution in events::solutions()
+                .into_iter()
+                .filter(|s| cli.year.is_none_or(|year| year == s.year))
+            {
+                println!(
+                    "{} Day {:02}: {}",
+                    solution.year, solution.day, solution.title
+                );
             }
             Ok(())
         }
-        AoCCommands::Create => day_generator.generate_day(
-            match cli.day {
+        AoCCommands::Puzzle => {
+            let mut input_fetcher = input_fetcher(&cli, &config);
+            print!("{}", input_fetcher.fetch_puzzle(day, year)?);
+            Ok(())
+        }
+        AoCCommands::Regenerate => day_generator.regenerate(),
+        AoCCommands::Create => {
+            let day = match cli.day {
                 Some(day) => day,
                 None => day + 1,
-            },
+            };
+            let mut input_fetcher = input_fetcher(&cli, &config);
+
+            let title = match input_fetcher.fetch_puzzle(day, year) {
+                Ok(puzzle) => html::puzzle_title(&puzzle).unwrap_or_default().to_string(),
+                Err(err) => {
+                    warn!("Could not fetch the puzzle title: {}", err);
+                    String::new()
+                }
+            };
+            day_generator.generate_day(day, year, &title)?;
+
+            // Records the example answers checked by the generated test, if the day is unlocked
+            if let Err(err) = input_fetcher.fetch(day, year, Some(1), &None) {
+                warn!("Could not fetch the example: {}", err);
+            }
+            Ok(())
+        }
+    };
+
+    if result.is_ok() {
+        for name in ctx.unused_params() {
+            warn!("No parameter named '{}', the --param was ignored", name);
+        }
+    }
+    result
+}
+
+fn parse_param(param: &str) -> Result<(String, String)> {
+    match param.split_once('=') {
+        Some((name, value)) => Ok((name.trim().into(), value.trim().into())),
+        None => anyhow::bail!("Expected name=value, got '{}'", param),
+    }
+}
+
+/// The known answers for the selected input. Examples and other inputs keep theirs in a file next
+/// to them, so they never end up in the ledger of the real input
+fn answer_ledger(cli: &AoCOptions, config: &Config, day: u8, year: u16) -> Result<AnswerLedger> {
+    match cli.test.is_some() || cli.input.is_some() {
+        true => AnswerLedger::load_file(InputFetcher::answers_path(&InputFetcher::input_path(
+            &config.input_folder,
+            day,
             year,
-        ),
+            cli.test,
+            &cli.input,
+        ))),
+        false => AnswerLedger::load(&config.input_folder),
+    }
+}
+
+fn input_fetcher(cli: &AoCOptions, config: &Config) -> InputFetcher {
+    let input_fetcher = InputFetcher::new(&config.input_folder)
+        .with_base_address(&config.base_address)
+        .with_cookie_file(&config.cookie_file)
+        .with_offline(cli.offline);
+
+    match &config.user_agent {
+        Some(user_agent) => input_fetcher.with_user_agent(user_agent),
+        None => input_fetcher,
     }
 }
@@ -0,0 +1,88 @@
+use std::{
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+    ops::Deref,
+    path::{Path, PathBuf},
+    thread::JoinHandle,
+};
+
+/// A fresh folder in the system temp dir, removed again on drop even when the test panics
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("aoc_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Serves one raw HTTP response per connection, in order, handing back every request it read.
+/// A status can carry extra headers, like `"302 Found\r\nLocation: /auth/login"`
+pub fn stub_server(
+    responses: Vec<(&'static str, &'static str)>,
+) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+
+    let server = std::thread::spawn(move || {
+        let mut requests = vec![];
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            requests.push(read_request(&mut stream));
+
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+        }
+        requests
+    });
+
+    (address, server)
+}
+
+/// Reads the headers, then as much body as they announce
+fn read_request(stream: &mut TcpStream) -> String {
+    let mut request = vec![];
+    let mut buffer = [0; 1024];
+
+    loop {
+        let text = String::from_utf8_lossy(&request);
+        if let Some(end) = text.find("\r\n\r\n") {
+            let length = text[..end]
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                .map_or(0, |(_, value)| value.trim().parse().unwrap());
+            if request.len() >= end + 4 + length {
+                return text.into();
+            }
+        }
+
+        let read = stream.read(&mut buffer).unwrap();
+        if read == 0 {
+            return text.into();
+        }
+        request.extend_from_slice(&buffer[..read]);
+    }
+}
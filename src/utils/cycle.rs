@@ -0,0 +1,211 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a sequence of states, with the first state at index 0, starts repeating
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// States before the first one that is part of the cycle
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The index of the first state equal to the n-th one
+    pub fn index_of(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.length,
+        }
+    }
+}
+
+/// Floyd's tortoise and hare, only keeps two states around
+pub fn floyd<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle {
+        start: prefix,
+        length,
+    }
+}
+
+/// Brent's algorithm, keeps two states around like [`floyd`] but steps fewer times
+pub fn brent<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle {
+        start: prefix,
+        length,
+    }
+}
+
+/// Remembers the key of every state, so each state is only computed once. Also returns the
+/// states up to the first repeat
+pub fn find_by_key<S, K: Eq + Hash>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut states = vec![start];
+
+    loop {
+        let state = states.last().unwrap();
+        let index = states.len() - 1;
+        let state_key = key(state);
+        if let Some(&first) = seen.get(&state_key) {
+            states.pop();
+            let cycle = Cycle {
+                start: first,
+                length: index - first,
+            };
+            return (cycle, states);
+        }
+
+        seen.insert(state_key, index);
+        let next = step(state);
+        states.push(next);
+    }
+}
+
+/// The n-th state, jumping ahead once the states repeat. Sequences that never repeat are simply
+/// stepped n times
+pub fn nth<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    // Brent's search, where the hare is at the index of the loop
+    let (mut power, mut length) = (1, 0);
+    let (mut tortoise, mut tortoise_index) = (start.clone(), 0);
+    let mut hare = start;
+
+    for index in 1..=n {
+        hare = step(&hare);
+        length += 1;
+
+        if hare == tortoise {
+            // Every state from the tortoise on repeats after `length` steps
+            let remaining = (n - tortoise_index) % length;
+            return (0..remaining).fold(tortoise, |state, _| step(&state));
+        }
+
+        if power == length {
+            tortoise = hare.clone();
+            tortoise_index = index;
+            power *= 2;
+            length = 0;
+        }
+    }
+
+    hare
+}
+
+/// Like [`nth`], for states that are compared by a key such as [`super::grid::Grid2D::hash_state`]
+pub fn nth_by_key<S, K: Eq + Hash>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+    n: usize,
+) -> S {
+    let mut seen = HashMap::new();
+    let mut states = vec![start];
+
+    // Stop as soon as either the n-th state or a repeat is reached
+    for index in 0.. {
+        let state = &states[index];
+        if index == n {
+            break;
+        }
+
+        let state_key = key(state);
+        if let Some(&first) = seen.get(&state_key) {
+            let cycle = Cycle {
+                start: first,
+                length: index - first,
+            };
+            return states.swap_remove(cycle.index_of(n));
+        }
+
+        seen.insert(state_key, index);
+        let next = step(state);
+        states.push(next);
+    }
+
+    states.pop().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycles() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 3
+        let step = |&x: &u32| if x == 6 { 3 } else { x + 1 };
+        let expected = Cycle {
+            start: 3,
+            length: 4,
+        };
+
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(brent(0, step), expected);
+
+        let (cycle, states) = find_by_key(0, step, |&x| x);
+        assert_eq!(cycle, expected);
+        assert_eq!(states, vec![0, 1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(nth(0, step, 2), 2);
+        assert_eq!(nth(0, step, 1_000_000_000), 3 + (1_000_000_000 - 3) % 4);
+        assert_eq!(nth_by_key(0, step, |&x| x, 5), 5);
+        assert_eq!(nth(0, |&x: &u64| x + 1, 5), 5);
+        for n in 0..20 {
+            let stepped = (0..n).fold(0, |x, _| step(&x));
+            assert_eq!(nth(0, step, n), stepped);
+            assert_eq!(nth_by_key(0, step, |&x| x, n), stepped);
+        }
+        assert_eq!(
+            nth_by_key(0, step, |&x| x, 1_000_000_001),
+            nth(0, step, 1_000_000_001)
+        );
+    }
+}
@@ -3,7 +3,7 @@ use std::{
     hash::{DefaultHasher, Hasher},
 };
 
-use super::Point;
+use super::{Point, vec2d::Vec2D};
 
 #[derive(Debug, Clone)]
 pub struct Grid2D<T: Clone + Display> {
@@ -168,14 +168,102 @@ impl<T: Clone + Display> Grid2D<T> {
     }
 
     pub fn get_row(&self, row: usize) -> impl IntoIterator<Item = &T> {
-        let offset = row * self.row_width;
-        self.backing_vec[offset..offset + self.row_width].iter()
+        self.row_slice(row)
     }
 
     pub fn get_col(&self, col: usize) -> impl IntoIterator<Item = &T> {
+        self.col_iter(col)
+    }
+
+    pub fn row_slice(&self, row: usize) -> &[T] {
+        let offset = row * self.row_width;
+        &self.backing_vec[offset..offset + self.row_width]
+    }
+
+    pub fn row_slice_mut(&mut self, row: usize) -> &mut [T] {
+        let offset = row * self.row_width;
+        &mut self.backing_vec[offset..offset + self.row_width]
+    }
+
+    pub fn col_iter(&self, col: usize) -> impl Iterator<Item = &T> {
         self.backing_vec[col..].iter().step_by(self.row_width)
     }
 
+    pub fn col_iter_mut(&mut self, col: usize) -> impl Iterator<Item = &mut T> {
+        self.backing_vec[col..].iter_mut().step_by(self.row_width)
+    }
+
+    /// A view of the rectangle with its top left corner at `origin`, if it fits inside the grid
+    pub fn subgrid<P: Into<Point>>(
+        &self,
+        origin: P,
+        width: usize,
+        height: usize,
+    ) -> Option<SubGrid<'_, T>> {
+        let start = self.subgrid_start(origin.into(), width, height)?;
+        Some(SubGrid {
+            backing: &self.backing_vec[start..],
+            stride: self.row_width,
+            width,
+            height,
+        })
+    }
+
+    pub fn subgrid_mut<P: Into<Point>>(
+        &mut self,
+        origin: P,
+        width: usize,
+        height: usize,
+    ) -> Option<SubGridMut<'_, T>> {
+        let start = self.subgrid_start(origin.into(), width, height)?;
+        Some(SubGridMut {
+            backing: &mut self.backing_vec[start..],
+            stride: self.row_width,
+            width,
+            height,
+        })
+    }
+
+    fn subgrid_start(&self, origin: Point, width: usize, height: usize) -> Option<usize> {
+        match origin.x + width <= self.width() && origin.y + height <= self.height() {
+            true => Some(origin.y * self.row_width + origin.x),
+            false => None,
+        }
+    }
+
+    /// Swaps rows and columns, mirroring the grid along its main diagonal
+    pub fn transpose(&self) -> Self {
+        Self::build(self.height(), self.width(), |x, y| self.at(y, x))
+    }
+
+    /// Rotates clockwise, the top row becomes the right column
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height();
+        Self::build(height, self.width(), |x, y| self.at(y, height - 1 - x))
+    }
+
+    /// Rotates counter-clockwise, the top row becomes the left column
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width();
+        Self::build(self.height(), width, |x, y| self.at(width - 1 - y, x))
+    }
+
+    /// Mirrors left to right
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width();
+        Self::build(width, self.height(), |x, y| self.at(width - 1 - x, y))
+    }
+
+    /// Mirrors top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height();
+        Self::build(self.width(), height, |x, y| self.at(x, height - 1 - y))
+    }
+
+    fn at(&self, x: usize, y: usize) -> T {
+        self.backing_vec[y * self.row_width + x].clone()
+    }
+
     pub fn swap<P: Into<Point>>(&mut self, src: P, dest: P) {
         let src = src.into();
         let dest = dest.into();
@@ -188,6 +276,30 @@ impl<T: Clone + Display> Grid2D<T> {
         }
     }
 
+    /// The orthogonal neighbours of a point that are inside the grid
+    pub fn neighbours4<P: Into<Point>>(&self, p: P) -> Neighbours<'_, T> {
+        self.neighbours(p, &Vec2D::STRAIGHT)
+    }
+
+    /// All eight neighbours of a point that are inside the grid
+    pub fn neighbours8<P: Into<Point>>(&self, p: P) -> Neighbours<'_, T> {
+        self.neighbours(p, &Vec2D::ADJACENT)
+    }
+
+    /// The points at each offset of the stencil that are inside the grid
+    pub fn neighbours<'a, P: Into<Point>>(
+        &'a self,
+        p: P,
+        stencil: &'a [Vec2D],
+    ) -> Neighbours<'a, T> {
+        Neighbours {
+            grid: self,
+            center: p.into(),
+            stencil: stencil.iter(),
+            wrapping: false,
+        }
+    }
+
     pub fn point_iter(&self) -> PointIter {
         PointIter {
             len: self.backing_vec.len(),
@@ -218,6 +330,147 @@ impl Iterator for PointIter {
     }
 }
 
+/// Iterator over the neighbours of a point, see [`Grid2D::neighbours`]
+pub struct Neighbours<'a, T: Clone + Display> {
+    grid: &'a Grid2D<T>,
+    center: Point,
+    stencil: std::slice::Iter<'a, Vec2D>,
+    wrapping: bool,
+}
+
+impl<T: Clone + Display> Neighbours<'_, T> {
+    /// Treats the grid as a torus, so offsets leaving one edge come back in on the opposite one
+    pub fn wrapping(mut self) -> Self {
+        self.wrapping = true;
+        self
+    }
+}
+
+impl<'a, T: Clone + Display> Iterator for Neighbours<'a, T> {
+    type Item = (Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &offset in self.stencil.by_ref() {
+            let point = match self.wrapping {
+                true => {
+                    let (width, height) = (self.grid.width() as i64, self.grid.height() as i64);
+                    Some(Point::new(
+                        (self.center.x() as i64 + offset.x()).rem_euclid(width) as usize,
+                        (self.center.y() as i64 + offset.y()).rem_euclid(height) as usize,
+                    ))
+                }
+                false => self.center.checked_add(offset),
+            };
+
+            if let Some(point) = point
+                && let Some(item) = self.grid.get(point)
+            {
+                return Some((point, item));
+            }
+        }
+
+        None
+    }
+}
+
+/// A borrowed rectangle of a grid, see [`Grid2D::subgrid`]
+pub struct SubGrid<'a, T> {
+    backing: &'a [T],
+    stride: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> SubGrid<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get<P: Into<Point>>(&self, p: P) -> Option<&'a T> {
+        let Point { x, y } = p.into();
+        match x < self.width && y < self.height {
+            true => Some(&self.backing[y * self.stride + x]),
+            false => None,
+        }
+    }
+
+    /// A row of the view, the parent rows past its height are not part of it
+    pub fn row(&self, row: usize) -> Option<&'a [T]> {
+        let offset = row * self.stride;
+        match row < self.height {
+            true => Some(&self.backing[offset..offset + self.width]),
+            false => None,
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let (backing, width) = (self.backing, self.width);
+        backing
+            .chunks(self.stride)
+            .take(self.height)
+            .map(move |row| &row[..width])
+    }
+}
+
+impl<T: Clone + Display> SubGrid<'_, T> {
+    pub fn to_grid(&self) -> Grid2D<T> {
+        Grid2D::from_raw(self.rows().flatten().cloned().collect(), self.width)
+    }
+}
+
+/// A mutably borrowed rectangle of a grid, see [`Grid2D::subgrid_mut`]
+pub struct SubGridMut<'a, T> {
+    backing: &'a mut [T],
+    stride: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<T> SubGridMut<'_, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get_mut<P: Into<Point>>(&mut self, p: P) -> Option<&mut T> {
+        let Point { x, y } = p.into();
+        match x < self.width && y < self.height {
+            true => Some(&mut self.backing[y * self.stride + x]),
+            false => None,
+        }
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> Option<&mut [T]> {
+        let offset = row * self.stride;
+        match row < self.height {
+            true => Some(&mut self.backing[offset..offset + self.width]),
+            false => None,
+        }
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let width = self.width;
+        self.backing
+            .chunks_mut(self.stride)
+            .take(self.height)
+            .map(move |row| &mut row[..width])
+    }
+
+    pub fn fill(&mut self, item: T)
+    where
+        T: Clone,
+    {
+        self.rows_mut().for_each(|row| row.fill(item.clone()));
+    }
+}
+
 impl<T: Clone + Display + std::hash::Hash> Grid2D<T> {
     pub fn hash_state(&self) -> u64 {
         use std::hash::Hash;
@@ -240,3 +493,104 @@ impl<T: Clone + Display> Display for Grid2D<T> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid2D::build(3, 3, |x, y| y * 3 + x);
+
+        let corner: Vec<_> = grid.neighbours4((0, 0)).map(|(_, &v)| v).collect();
+        assert_eq!(corner, vec![1, 3]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((2, 2)).map(|(_, &v)| v).sum::<usize>(),
+            5 + 7 + 4
+        );
+
+        let wrapped: Vec<_> = grid.neighbours4((0, 0)).wrapping().collect();
+        assert_eq!(
+            wrapped,
+            vec![
+                (Point::new(0, 2), &6),
+                (Point::new(1, 0), &1),
+                (Point::new(0, 1), &3),
+                (Point::new(2, 0), &2)
+            ]
+        );
+
+        let knight = [Vec2D::new(1, 2), Vec2D::new(2, 1), Vec2D::new(-1, 2)];
+        let jumps: Vec<_> = grid.neighbours((0, 0), &knight).map(|(p, _)| p).collect();
+        assert_eq!(jumps, vec![Point::new(1, 2), Point::new(2, 1)]);
+    }
+
+    #[test]
+    fn transformations() {
+        // 0 1 2
+        // 3 4 5
+        let grid = Grid2D::build(3, 2, |x, y| y * 3 + x);
+        let rows = |grid: &Grid2D<usize>| {
+            (0..grid.height())
+                .map(|row| grid.row_slice(row).to_vec())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            rows(&grid.transpose()),
+            vec![vec![0, 3], vec![1, 4], vec![2, 5]]
+        );
+        assert_eq!(
+            rows(&grid.rotate_cw()),
+            vec![vec![3, 0], vec![4, 1], vec![5, 2]]
+        );
+        assert_eq!(
+            rows(&grid.rotate_ccw()),
+            vec![vec![2, 5], vec![1, 4], vec![0, 3]]
+        );
+        assert_eq!(
+            rows(&grid.flip_horizontal()),
+            vec![vec![2, 1, 0], vec![5, 4, 3]]
+        );
+        assert_eq!(
+            rows(&grid.flip_vertical()),
+            vec![vec![3, 4, 5], vec![0, 1, 2]]
+        );
+        assert_eq!(
+            rows(&grid.rotate_cw().rotate_cw()),
+            rows(&grid.flip_horizontal().flip_vertical())
+        );
+        assert_eq!(grid.col_iter(1).copied().collect::<Vec<_>>(), vec![1, 4]);
+
+        let mut grid = grid;
+        let view = grid.subgrid((1, 0), 2, 2).unwrap();
+        assert_eq!(view.rows().collect::<Vec<_>>(), vec![&[1, 2], &[4, 5]]);
+        assert_eq!(view.get((1, 1)), Some(&5));
+        assert_eq!(view.get((2, 0)), None);
+        assert!(grid.subgrid((2, 0), 2, 1).is_none());
+
+        grid.subgrid_mut((1, 1), 2, 1).unwrap().fill(9);
+        grid.col_iter_mut(0).for_each(|v| *v += 10);
+        assert_eq!(rows(&grid), vec![vec![10, 1, 2], vec![13, 9, 9]]);
+    }
+
+    #[test]
+    fn subgrid_rows_stay_inside() {
+        let mut grid = Grid2D::build(3, 3, |x, y| y * 3 + x);
+
+        let view = grid.subgrid((1, 0), 2, 2).unwrap();
+        assert_eq!(view.row(1), Some(&[4, 5][..]));
+        assert_eq!(view.row(2), None);
+        assert_eq!(view.rows().count(), 2);
+
+        let mut view = grid.subgrid_mut((0, 1), 2, 1).unwrap();
+        view.row_mut(0).unwrap().fill(0);
+        assert!(view.row_mut(1).is_none());
+        assert!(view.get_mut((0, 1)).is_none());
+        assert_eq!(view.rows_mut().count(), 1);
+
+        assert_eq!(grid.row_slice(1), &[0, 0, 5]);
+        assert_eq!(grid.row_slice(2), &[6, 7, 8]);
+    }
+}
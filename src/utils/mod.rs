@@ -1,8 +1,11 @@
 pub mod aoc_result;
+pub mod cycle;
 pub mod grid;
 pub mod math_utils;
 pub mod point;
+pub mod search;
 pub mod slice_utils;
+pub mod sparse_grid;
 pub mod variable;
 pub mod vec2d;
 
@@ -0,0 +1,254 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// The goal reached by a search, with what is needed to reconstruct the path to it
+pub struct Found<S> {
+    pub cost: usize,
+    pub goal: S,
+    parents: HashMap<S, S>,
+}
+
+impl<S: Clone + Eq + Hash> Found<S> {
+    /// The states from the start to the goal, both included
+    pub fn path(&self) -> Vec<S> {
+        let mut path = vec![self.goal.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+/// Finds the goal reachable in the fewest steps
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, cost)) = queue.pop_front() {
+        if goal(&state) {
+            return Some(Found {
+                cost,
+                goal: state,
+                parents,
+            });
+        }
+
+        for next in successors(&state) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), state.clone());
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the cheapest goal, the successors yield each next state with the cost to step there
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(start, successors, |_| 0, goal)
+}
+
+/// Like [`dijkstra`], guided by a heuristic that must never overestimate the remaining cost
+pub fn astar<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut parents = HashMap::new();
+
+    // The frontier refers to states by index, so they do not have to be ordered
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut states = vec![start];
+
+    while let Some(Reverse((_, cost, index))) = frontier.pop() {
+        let state = states[index].clone();
+
+        // A cheaper way to this state was found after this one was queued
+        if costs[&state] < cost {
+            continue;
+        }
+
+        if goal(&state) {
+            return Some(Found {
+                cost,
+                goal: state,
+                parents,
+            });
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_some_and(|&best| best <= next_cost) {
+                continue;
+            }
+
+            costs.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), state.clone());
+            frontier.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                states.len(),
+            )));
+            states.push(next);
+        }
+    }
+
+    None
+}
+
+/// Counts the distinct paths from the start that end in a goal. Paths stop at the first goal, and
+/// panics when the successors lead back to a state on the same path
+pub fn count_paths<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> usize
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    if goal(&start) {
+        return 1;
+    }
+
+    let mut known = HashMap::new();
+    let mut on_path = HashSet::from([start.clone()]);
+
+    // A state on the current path, the successors left to count and the paths found so far
+    let next = successors(&start).into_iter();
+    let mut stack = vec![(start, next, 0)];
+
+    while let Some((_, next, paths)) = stack.last_mut() {
+        match next.next() {
+            Some(state) => {
+                if let Some(&known_paths) = known.get(&state) {
+                    *paths += known_paths;
+                } else if goal(&state) {
+                    *paths += 1;
+                } else if !on_path.insert(state.clone()) {
+                    panic!(
+                        "count_paths found a cycle, the successors lead back to an earlier state"
+                    );
+                } else {
+                    let next = successors(&state).into_iter();
+                    stack.push((state, next, 0));
+                }
+            }
+            None => {
+                let (state, _, paths) = stack.pop().unwrap();
+                on_path.remove(&state);
+                known.insert(state, paths);
+
+                match stack.last_mut() {
+                    Some((_, _, parent_paths)) => *parent_paths += paths,
+                    None => return paths,
+                }
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{Point, grid::Grid2D};
+
+    #[test]
+    fn searches() {
+        let input: Vec<String> = ["..#.", ".##.", "....", "#1.."]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let grid = Grid2D::parse_char(&input, |c| c.to_digit(10).unwrap_or(0) as usize + 1);
+        let walls = Grid2D::parse_char(&input, |c| c == '#');
+
+        let open = |p: &Point| {
+            walls
+                .neighbours4(*p)
+                .filter(|(_, wall)| !**wall)
+                .map(|(p, _)| p)
+                .collect::<Vec<_>>()
+        };
+        let end = Point::new(3, 0);
+
+        let found = bfs(Point::new(0, 0), open, |p| *p == end).unwrap();
+        assert_eq!(found.cost, 7);
+        assert_eq!(found.path().len(), 8);
+        assert_eq!(found.path()[0], Point::new(0, 0));
+
+        let weighted = |p: &Point| {
+            open(p)
+                .into_iter()
+                .map(|n| (n, *grid.get(n).unwrap()))
+                .collect::<Vec<_>>()
+        };
+        let end = Point::new(3, 3);
+        let cheapest = dijkstra(Point::new(0, 0), weighted, |p| *p == end).unwrap();
+        let guided = astar(
+            Point::new(0, 0),
+            weighted,
+            |p| p.manhattan_distance(&end),
+            |p| *p == end,
+        )
+        .unwrap();
+        assert_eq!(cheapest.cost, 6);
+        assert_eq!(guided.cost, 6);
+        assert!(!cheapest.path().contains(&Point::new(1, 3)));
+
+        let right_or_down = |p: &Point| {
+            [p.add_x(1), p.add_y(1)]
+                .into_iter()
+                .flatten()
+                .filter(|p| p.x() <= 2 && p.y() <= 2)
+        };
+        let paths = count_paths(Point::new(0, 0), right_or_down, |p| *p == Point::new(2, 2));
+        assert_eq!(paths, 6);
+    }
+
+    #[test]
+    fn count_long_paths() {
+        // Climbing 30 stairs one or two at a time
+        let stairs = |&x: &u32| [x + 1, x + 2].into_iter().filter(|&x| x <= 30);
+        assert_eq!(count_paths(0, stairs, |&x| x == 30), 1_346_269);
+
+        let chain = count_paths(0, |&x: &u32| [x + 1], |&x| x == 100_000);
+        assert_eq!(chain, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cycle")]
+    fn count_paths_cycle() {
+        count_paths(0, |&x: &u32| [(x + 1) % 3], |&x| x == 5);
+    }
+}
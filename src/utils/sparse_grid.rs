@@ -0,0 +1,201 @@
+use std::{collections::HashMap, fmt::Display};
+
+use super::{Point, grid::Grid2D, point::IPoint};
+
+/// An unbounded grid that only stores occupied cells, for coordinates that can be negative or
+/// keep growing
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<IPoint, T>,
+    bounds: Option<(IPoint, IPoint)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn insert(&mut self, p: IPoint, item: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                IPoint::new(min.x.min(p.x), min.y.min(p.y)),
+                IPoint::new(max.x.max(p.x), max.y.max(p.y)),
+            ),
+            None => (p, p),
+        });
+
+        self.cells.insert(p, item)
+    }
+
+    pub fn remove(&mut self, p: &IPoint) -> Option<T> {
+        let item = self.cells.remove(p)?;
+
+        // Only a cell on the edge can shrink the bounding box
+        if let Some((min, max)) = self.bounds
+            && (p.x == min.x || p.x == max.x || p.y == min.y || p.y == max.y)
+        {
+            self.bounds = self.cells.keys().fold(None, |bounds, p| {
+                Some(match bounds {
+                    Some((min, max)) => (
+                        IPoint::new(p.x.min(min.x), p.y.min(min.y)),
+                        IPoint::new(p.x.max(max.x), p.y.max(max.y)),
+                    ),
+                    None => (*p, *p),
+                })
+            });
+        }
+
+        Some(item)
+    }
+
+    pub fn get(&self, p: &IPoint) -> Option<&T> {
+        self.cells.get(p)
+    }
+
+    pub fn get_mut(&mut self, p: &IPoint) -> Option<&mut T> {
+        self.cells.get_mut(p)
+    }
+
+    pub fn contains(&self, p: &IPoint) -> bool {
+        self.cells.contains_key(p)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&IPoint, &T)> {
+        self.cells.iter()
+    }
+
+    /// The top left and bottom right corners of the occupied region, both included
+    pub fn bounds(&self) -> Option<(IPoint, IPoint)> {
+        self.bounds
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+}
+
+impl<T: Clone + Display> SparseGrid<T> {
+    /// Copies the cells of a grid that should be kept, like the walls of a maze
+    pub fn from_grid<F: Fn(&T) -> bool>(grid: &Grid2D<T>, keep: F) -> Self {
+        grid.point_iter()
+            .map(|p| (p, grid.get(p).unwrap()))
+            .filter(|(_, item)| keep(item))
+            .map(|(p, item)| (IPoint::new(p.x() as i64, p.y() as i64), item.clone()))
+            .collect()
+    }
+
+    /// A dense copy of the occupied region, with the top left corner of the bounds at `(0, 0)`
+    pub fn to_grid(&self, empty: T) -> Option<Grid2D<T>> {
+        let (min, _) = self.bounds?;
+        Some(Grid2D::build(self.width(), self.height(), |x, y| {
+            let p = IPoint::new(min.x + x as i64, min.y + y as i64);
+            self.cells.get(&p).unwrap_or(&empty).clone()
+        }))
+    }
+
+    /// The dense grid position of a point, see [`SparseGrid::to_grid`]
+    pub fn grid_point(&self, p: &IPoint) -> Option<Point> {
+        let (min, max) = self.bounds?;
+        match (min.x..=max.x).contains(&p.x) && (min.y..=max.y).contains(&p.y) {
+            true => Some(Point::new((p.x - min.x) as usize, (p.y - min.y) as usize)),
+            false => None,
+        }
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(IPoint, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (IPoint, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(IPoint, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (IPoint, T)>>(&mut self, iter: I) {
+        for (p, item) in iter {
+            self.insert(p, item);
+        }
+    }
+}
+
+impl<T: Clone + Display> From<&Grid2D<T>> for SparseGrid<T> {
+    fn from(grid: &Grid2D<T>) -> Self {
+        Self::from_grid(grid, |_| true)
+    }
+}
+
+/// Renders the occupied region, empty cells are shown as `.`
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.cells.get(&IPoint::new(x, y)) {
+                    Some(item) => write!(f, "{}", item)?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_and_conversions() {
+        let mut grid: SparseGrid<char> = [(IPoint::new(-2, 1), 'a'), (IPoint::new(1, -1), 'b')]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            grid.bounds(),
+            Some((IPoint::new(-2, -1), IPoint::new(1, 1)))
+        );
+        assert_eq!(format!("{}", grid), "\n...b\n....\na...\n");
+
+        grid.insert(IPoint::new(0, 0), 'c');
+        grid.remove(&IPoint::new(-2, 1));
+        assert_eq!(grid.bounds(), Some((IPoint::new(0, -1), IPoint::new(1, 0))));
+        assert_eq!(grid.grid_point(&IPoint::new(0, 0)), Some(Point::new(0, 1)));
+
+        let dense = grid.to_grid(' ').unwrap();
+        assert_eq!(format!("{}", dense), "\n b\nc \n");
+
+        let back = SparseGrid::from_grid(&dense, |&c| c != ' ');
+        assert_eq!(back.len(), 2);
+        assert_eq!(back.get(&IPoint::new(1, 0)), Some(&'b'));
+        assert!(SparseGrid::<char>::new().to_grid('.').is_none());
+    }
+}
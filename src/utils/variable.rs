@@ -1,41 +1,110 @@
-use std::ops::Deref;
+use std::{fmt::Display, str::FromStr};
 
-pub struct Variable<T> {
+use anyhow::{Context, Result};
+use tracing::debug;
+
+use crate::events::RunContext;
+
+/// A parameter that differs between the example and the live input. Named parameters can also
+/// have values for specific inputs, and be overridden with `--param name=value`
+pub struct Variable<T: 'static> {
+    name: Option<&'static str>,
     test: T,
     live: T,
+    inputs: &'static [(&'static str, T)],
 }
 
 pub const fn variable<T>(test: T, live: T) -> Variable<T> {
-    Variable { test, live }
+    Variable {
+        name: None,
+        test,
+        live,
+        inputs: &[],
+    }
 }
 
-impl<T> Deref for Variable<T> {
-    type Target = T;
-
-    fn deref(&self) -> &Self::Target {
-        let is_test = unsafe { crate::TEST };
-        if is_test {
-            &self.test
-        } else {
-            &self.live
-        }
+pub const fn param<T>(name: &'static str, test: T, live: T) -> Variable<T> {
+    Variable {
+        name: Some(name),
+        test,
+        live,
+        inputs: &[],
     }
 }
 
-impl<T: std::cmp::PartialEq> PartialEq<T> for Variable<T> {
-    fn eq(&self, other: &T) -> bool {
-        self.deref() == other
+impl<T> Variable<T> {
+    /// Values for inputs selected with `--input <name>`
+    pub const fn with_inputs(mut self, inputs: &'static [(&'static str, T)]) -> Self {
+        self.inputs = inputs;
+        self
     }
 }
 
-impl<T: std::cmp::PartialOrd> PartialOrd<T> for Variable<T> {
-    fn partial_cmp(&self, other: &T) -> Option<std::cmp::Ordering> {
-        self.deref().partial_cmp(other)
+impl<T: Clone + Display + FromStr> Variable<T>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    pub fn get(&self, ctx: &RunContext) -> Result<T> {
+        if let Some(name) = self.name {
+            ctx.use_param(name);
+        }
+
+        let overridden = self
+            .name
+            .and_then(|name| ctx.params.get(name).map(|value| (name, value)));
+        let for_input = ctx
+            .input
+            .as_ref()
+            .and_then(|input| self.inputs.iter().find(|(name, _)| name == input));
+
+        let (value, source) = match (overridden, for_input) {
+            (Some((name, value)), _) => (
+                value
+                    .parse()
+                    .with_context(|| format!("Invalid value '{}' for param {}", value, name))?,
+                "override",
+            ),
+            (None, Some((input, value))) => (value.clone(), *input),
+            (None, None) if ctx.test => (self.test.clone(), "test"),
+            (None, None) => (self.live.clone(), "live"),
+        };
+
+        if let Some(name) = self.name {
+            debug!("Param {} = {} ({})", name, value, source);
+        }
+
+        Ok(value)
     }
 }
 
-// impl<T: std::cmp::Ord> Ord for Variable<T> {
-//     fn cmp(&self, other: &T) -> Option<std::cmp::Ordering> {
-//         self.deref().cmp(other)
-//     }
-// }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn per_context() {
+        const DIST: Variable<usize> = variable(32, 10000);
+
+        assert_eq!(DIST.get(&RunContext::test()).unwrap(), 32);
+        assert_eq!(DIST.get(&RunContext::live()).unwrap(), 10000);
+    }
+
+    #[test]
+    fn inputs_and_overrides() {
+        const STEPS: Variable<usize> = param("steps", 6, 64).with_inputs(&[("small", 10)]);
+
+        let mut ctx = RunContext {
+            input: Some("small".into()),
+            ..RunContext::live()
+        };
+        ctx.params.insert("stesp".into(), "100".into());
+        assert_eq!(STEPS.get(&ctx).unwrap(), 10);
+        assert_eq!(ctx.unused_params(), vec!["stesp"]);
+
+        ctx.params.insert("steps".into(), "100".into());
+        assert_eq!(STEPS.get(&ctx).unwrap(), 100);
+
+        ctx.params.insert("steps".into(), "many".into());
+        assert!(STEPS.get(&ctx).is_err());
+    }
+}
@@ -12,6 +12,20 @@ impl Vec2D {
     pub const LEFT: Vec2D = Vec2D::new(-1, 0);
     pub const RIGHT: Vec2D = Vec2D::new(1, 0);
 
+    /// Offsets of the four orthogonal neighbours, clockwise from up
+    pub const STRAIGHT: [Vec2D; 4] = [Vec2D::UP, Vec2D::RIGHT, Vec2D::DOWN, Vec2D::LEFT];
+    /// Offsets of all eight neighbours, clockwise from up
+    pub const ADJACENT: [Vec2D; 8] = [
+        Vec2D::UP,
+        Vec2D::new(1, -1),
+        Vec2D::RIGHT,
+        Vec2D::new(1, 1),
+        Vec2D::DOWN,
+        Vec2D::new(-1, 1),
+        Vec2D::LEFT,
+        Vec2D::new(-1, -1),
+    ];
+
     pub const fn new(x: i64, y: i64) -> Self {
         Self { x, y }
     }